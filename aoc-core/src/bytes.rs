@@ -0,0 +1,68 @@
+//! Helpers for taking inputs apart in `const fn`s, where there are no iterators and none
+//! of the `str` methods that need them. Everything works on byte strings and indices.
+
+/// Bytes `start..end` of a byte string
+pub const fn slice(bytes: &[u8], start: usize, end: usize) -> &[u8] {
+    bytes.split_at(end).0.split_at(start).1
+}
+
+/// The line starting at some index, without its line break (like [`str::lines`] strips
+/// `\n` and `\r\n`), and the index of the next line
+pub const fn line_at(bytes: &[u8], start: usize) -> (&[u8], usize) {
+    let mut end = start;
+    while end < bytes.len() && bytes[end] != b'\n' {
+        end += 1;
+    }
+    let next = end + 1;
+    if end > start && bytes[end - 1] == b'\r' {
+        end -= 1;
+    }
+    (slice(bytes, start, end), next)
+}
+
+/// Whether a byte string continues with some prefix at an index
+pub const fn starts_with_at(bytes: &[u8], idx: usize, prefix: &[u8]) -> bool {
+    if idx + prefix.len() > bytes.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[idx + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The index of the next byte at or after an index that isn't a space
+pub const fn skip_spaces(bytes: &[u8], mut idx: usize) -> usize {
+    while idx < bytes.len() && bytes[idx] == b' ' {
+        idx += 1;
+    }
+    idx
+}
+
+/// The index of the first occurrence of a byte at or after an index
+pub const fn find(bytes: &[u8], mut idx: usize, byte: u8) -> Option<usize> {
+    while idx < bytes.len() {
+        if bytes[idx] == byte {
+            return Some(idx);
+        }
+        idx += 1;
+    }
+    None
+}
+
+/// Parse the decimal number starting at an index, returning it and the index after it
+pub const fn parse_number(bytes: &[u8], mut idx: usize) -> (usize, usize) {
+    if idx >= bytes.len() || !bytes[idx].is_ascii_digit() {
+        panic!("expected a number");
+    }
+    let mut num = 0;
+    while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+        num = 10 * num + (bytes[idx] - b'0') as usize;
+        idx += 1;
+    }
+    (num, idx)
+}
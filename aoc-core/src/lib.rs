@@ -0,0 +1,12 @@
+//! The pure logic of the solutions: parsing the inputs and solving the puzzles.
+//!
+//! Everything here only needs `alloc`, so there's no `regex` or `once_cell` and the maps
+//! and sets are B-trees. Input validation, loading and all the other tooling live in the
+//! `aoc` crate, which re-exports these modules.
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod bytes;
+pub mod y2023;
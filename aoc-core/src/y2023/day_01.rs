@@ -1,16 +1,16 @@
-use aoc::input::day_01::INPUT;
+use alloc::{format, string::String};
 
-fn main() {
-    dbg!(fst(INPUT));
-    dbg!(snd(INPUT));
-}
+use crate::bytes;
 
-pub fn fst(raw: &'static str) -> usize {
+pub fn fst(raw: &str) -> usize {
     raw.lines()
         .map(|line| {
             let mut chars = line.chars().filter(|c| c.is_ascii_digit());
-            let first = chars.next().unwrap();
-            let last = chars.last().unwrap_or(first);
+            // lines without digits are only meant for part 2 (like the second example)
+            let Some(first) = chars.next() else {
+                return 0;
+            };
+            let last = chars.next_back().unwrap_or(first);
             format!("{first}{last}").parse::<usize>().unwrap()
         })
         .sum::<usize>()
@@ -29,6 +29,10 @@ pub fn parse_digits(line: &str) -> impl Iterator<Item = usize> + '_ {
     /// Drops at least one characters and as many as necessary for current to be a valid prefix to a numeral
     fn drop_until_valid_numeral_prefix(current: &mut String) {
         for new_start in 1..current.len() {
+            if !current.is_char_boundary(new_start) {
+                // there's no numeral starting in the middle of a multibyte character
+                continue;
+            }
             let tail = &current[new_start..];
             if is_numeral_prefix(tail) {
                 // the current subview of the buffer is a prefix for some numeral:
@@ -79,7 +83,7 @@ pub fn parse_digits(line: &str) -> impl Iterator<Item = usize> + '_ {
         .flatten()
 }
 
-pub fn snd(raw: &'static str) -> usize {
+pub fn snd(raw: &str) -> usize {
     raw.lines()
         .map(|line| {
             // we create an iterator over all the (parsed) digits in the line
@@ -91,9 +95,68 @@ pub fn snd(raw: &'static str) -> usize {
             let last = digits.last().unwrap_or(first);
             // going through format here is hacky and we could go through simple
             // ascii instead but eh - I'm lazy
-            let val: usize = format!("{first}{last}").parse().unwrap();
-            // println!("{line} = {val}");
-            val
+            format!("{first}{last}").parse::<usize>().unwrap()
         })
         .sum::<usize>()
 }
+
+/// The digit starting at some index of a line, also counting spelled out ones if `words`
+/// is set. Together with scanning every index this is the `const` counterpart of
+/// [`parse_digits`].
+pub const fn digit_at(line: &[u8], idx: usize, words: bool) -> Option<usize> {
+    if line[idx].is_ascii_digit() {
+        return Some((line[idx] - b'0') as usize);
+    }
+    if words {
+        let mut num = 0;
+        while num < NUMERALS.len() {
+            if bytes::starts_with_at(line, idx, NUMERALS[num].as_bytes()) {
+                return Some(num);
+            }
+            num += 1;
+        }
+    }
+    None
+}
+
+/// The first and last digit of a line as a two-digit number
+const fn calibration_value(line: &[u8], words: bool) -> usize {
+    let (mut first, mut last) = (None, 0);
+    let mut idx = 0;
+    while idx < line.len() {
+        if let Some(digit) = digit_at(line, idx, words) {
+            if first.is_none() {
+                first = Some(digit);
+            }
+            last = digit;
+        }
+        idx += 1;
+    }
+    match first {
+        Some(first) => 10 * first + last,
+        // lines without digits are only meant for part 2 (see `fst`)
+        None if !words => 0,
+        None => panic!("line without digits"),
+    }
+}
+
+const fn calibration_sum(input: &str, words: bool) -> usize {
+    let input = input.as_bytes();
+    let (mut sum, mut start) = (0, 0);
+    while start < input.len() {
+        let (line, next) = bytes::line_at(input, start);
+        sum += calibration_value(line, words);
+        start = next;
+    }
+    sum
+}
+
+/// [`fst`] in `const` context
+pub const fn fst_const(input: &str) -> usize {
+    calibration_sum(input, false)
+}
+
+/// [`snd`] in `const` context
+pub const fn snd_const(input: &str) -> usize {
+    calibration_sum(input, true)
+}
@@ -1,12 +1,8 @@
-use std::{collections::HashMap, str::FromStr};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::str::FromStr;
 
-use once_cell::sync::Lazy;
-use regex::Regex;
-
-use aoc::input::day_02::INPUT;
-
-#[derive(Hash, Debug, PartialEq, Eq, Clone, Copy)]
-enum Color {
+#[derive(Hash, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Color {
     Red,
     Green,
     Blue,
@@ -25,41 +21,34 @@ impl FromStr for Color {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Game {
-    game_id: usize,
-    draws: Vec<HashMap<Color, usize>>,
+pub struct Game {
+    pub game_id: usize,
+    pub draws: Vec<BTreeMap<Color, usize>>,
 }
 
 impl FromStr for Game {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        static GROUP_RE: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"Game (?P<game_id>\d+): (?P<tail>.+)$").unwrap());
-        static DRAW_RE: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"(?P<count>\d+) (?P<color>red|green|blue)").unwrap());
-
-        let captures = GROUP_RE.captures(s).unwrap();
-        let game_id: usize = captures["game_id"].parse().unwrap();
-        let draws = captures["tail"]
+        let (head, tail) = s.split_once(": ").ok_or(())?;
+        let game_id: usize = head.trim_start_matches("Game ").parse().map_err(|_| ())?;
+        let draws = tail
             .split(';')
             .map(|block| {
-                DRAW_RE
-                    .captures_iter(block)
-                    .map(|cap| {
-                        (
-                            cap["color"].parse().unwrap(),
-                            cap["count"].parse::<usize>().unwrap(),
-                        )
+                block
+                    .split(',')
+                    .map(|draw| {
+                        let (count, color) = draw.trim().split_once(' ').ok_or(())?;
+                        Ok((color.parse()?, count.parse::<usize>().map_err(|_| ())?))
                     })
                     .collect()
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         Ok(Game { game_id, draws })
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Bag(HashMap<Color, usize>);
+pub struct Bag(pub BTreeMap<Color, usize>);
 
 impl Game {
     /// Check whether this game is possible relative to a given magic bag:
@@ -80,11 +69,7 @@ impl Game {
     /// a game is possible if the total number for each color can actually
     /// be taken from the magic bag at once.
     pub fn minimal_possible_bag(&self) -> Bag {
-        let mut out = hash_map!(
-            Color::Red => 0usize,
-            Color::Green => 0,
-            Color::Blue => 0,
-        );
+        let mut out = BTreeMap::from([(Color::Red, 0usize), (Color::Green, 0), (Color::Blue, 0)]);
         self.draws.iter().for_each(|m| {
             for (&color, &count) in m {
                 out.entry(color)
@@ -97,47 +82,37 @@ impl Game {
 }
 
 impl Bag {
+    /// The bag of part one: 12 red, 13 green and 14 blue cubes
+    pub fn allowed() -> Self {
+        // yes this could've been a simple Vec3 together with the other color stuff
+        Self(BTreeMap::from([
+            (Color::Red, 12),
+            (Color::Green, 13),
+            (Color::Blue, 14),
+        ]))
+    }
+
     pub fn power(&self) -> usize {
         self.0.values().product()
     }
 }
 
-fn main() {
-    fst();
-    snd();
-}
-
-fn fst() {
-    // yes this could've been a simple Vec3 together with the other color stuff
-    let allowed = Bag(hash_map!(
-        Color::Red => 12,
-        Color::Green => 13,
-        Color::Blue => 14,
-    ));
-    dbg!(INPUT
+pub fn fst(input: &str) -> usize {
+    let allowed = Bag::allowed();
+    input
         .lines()
         .map(Game::from_str)
         .map(Result::unwrap)
         .filter(|game| game.is_possible(&allowed))
         .map(|game| game.game_id)
-        .sum::<usize>());
+        .sum::<usize>()
 }
 
-fn snd() {
-    dbg!(INPUT
+pub fn snd(input: &str) -> usize {
+    input
         .lines()
         .map(Game::from_str)
         .map(Result::unwrap)
         .map(|game| game.minimal_possible_bag().power())
-        .sum::<usize>());
-}
-
-mod macros {
-    #[macro_export]
-    /// Provides a convenient way to construct `HashMap`s
-    macro_rules! hash_map {
-        ($($key:expr => $val:expr),* $(,)?) => {
-            HashMap::from([$(($key, $val)),*])
-        };
-    }
+        .sum::<usize>()
 }
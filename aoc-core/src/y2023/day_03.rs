@@ -1,70 +1,87 @@
-#![feature(array_windows)]
 //  applying the refactor suggested by this lint makes the code quite a bit less readable
 #![allow(clippy::option_map_unit_fn)]
 
-use std::{
-    collections::{HashMap, HashSet},
-    ops::RangeInclusive,
-    str::FromStr,
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
 };
+use core::{cmp::Ordering, ops::RangeInclusive, str::FromStr};
 
-use aoc::input::day_03::INPUT;
 use itertools::Itertools;
 
-fn main() {
-    fst(INPUT);
-    snd(INPUT);
-}
-
-struct Schematic {
-    lines: Vec<SchematicLine>,
+pub struct Schematic {
+    pub lines: Vec<SchematicLine>,
 }
 
 #[derive(Hash, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct Symbol {
-    val: char,
-    line_idx: usize,
-    idx: usize,
+pub struct Symbol {
+    pub val: char,
+    pub line_idx: usize,
+    pub idx: usize,
 }
 
 #[derive(Hash, Clone, Debug, PartialEq, Eq)]
-struct Number {
-    val: usize,
-    line_idx: usize,
-    span: RangeInclusive<usize>,
+pub struct Number {
+    pub val: usize,
+    pub line_idx: usize,
+    pub span: RangeInclusive<usize>,
+}
+
+// ranges aren't ordered, so this has to be done by hand to put numbers into B-trees
+impl Ord for Number {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.line_idx, self.span.start(), self.span.end(), self.val).cmp(&(
+            other.line_idx,
+            other.span.start(),
+            other.span.end(),
+            other.val,
+        ))
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 #[derive(Hash, Clone, Debug, PartialEq, Eq)]
-enum SchematicEntry {
+pub enum SchematicEntry {
     Symbol(Symbol),
     Number(Number),
 }
 
-struct SchematicLine(Vec<SchematicEntry>);
+pub struct SchematicLine(pub Vec<SchematicEntry>);
 
 impl SchematicLine {
-    fn parse_line(line: &str, line_idx: usize) -> Self {
+    /// Parse a line of the schematic, failing if a number doesn't fit into a `u32`
+    pub fn parse_line(line: &str, line_idx: usize) -> Option<Self> {
         #[derive(Default)]
         struct NumParser {
             current_num: u32,
             current_start_idx: Option<usize>,
         }
         impl NumParser {
-            fn consume(self, digit: char, idx: usize) -> Self {
-                NumParser {
-                    current_num: 10 * self.current_num + digit.to_digit(10).unwrap(),
+            fn consume(self, digit: char, idx: usize) -> Option<Self> {
+                Some(NumParser {
+                    current_num: self
+                        .current_num
+                        .checked_mul(10)?
+                        .checked_add(digit.to_digit(10)?)?,
                     current_start_idx: if self.current_start_idx.is_some() {
                         self.current_start_idx
                     } else {
                         Some(idx)
                     },
-                }
+                })
             }
 
             fn try_finish(self, idx: usize, line_idx: usize) -> Option<SchematicEntry> {
                 self.current_start_idx.map(|start| {
                     SchematicEntry::Number(Number {
-                        val: self.current_num as usize, // idx should be nonzero - yolo
+                        val: self.current_num as usize,
+                        // a number only finishes after its digits, so idx > start
                         span: start..=idx - 1,
                         line_idx,
                     })
@@ -74,12 +91,12 @@ impl SchematicLine {
         let mut acc = vec![];
         line.chars()
             .enumerate()
-            .fold(NumParser::default(), |state, (idx, c)| match c {
+            .try_fold(NumParser::default(), |state, (idx, c)| match c {
                 d if d.is_ascii_digit() => state.consume(d, idx),
                 '.' => {
                     state.try_finish(idx, line_idx).map(|span| acc.push(span));
                     // acc.push(SchematicEntry::Dot);
-                    NumParser::default()
+                    Some(NumParser::default())
                 }
                 c => {
                     state.try_finish(idx, line_idx).map(|span| acc.push(span));
@@ -88,12 +105,13 @@ impl SchematicLine {
                         idx,
                         line_idx,
                     }));
-                    NumParser::default()
+                    Some(NumParser::default())
                 }
-            })
-            .try_finish(line.len(), line_idx)
+            })?
+            // the indices are of chars, so the end is too
+            .try_finish(line.chars().count(), line_idx)
             .map(|span| acc.push(span));
-        Self(acc)
+        Some(Self(acc))
     }
 }
 
@@ -106,7 +124,8 @@ impl FromStr for Schematic {
                 .lines()
                 .enumerate()
                 .map(|(line_idx, line)| SchematicLine::parse_line(line, line_idx))
-                .collect::<Vec<_>>(),
+                .collect::<Option<Vec<_>>>()
+                .ok_or(())?,
         })
     }
 }
@@ -125,7 +144,7 @@ fn vertical_l1_distance(point: usize, line: &RangeInclusive<usize>) -> usize {
 }
 
 impl Schematic {
-    pub fn adjacencies(&self) -> HashMap<&Symbol, HashSet<&Number>> {
+    pub fn adjacencies(&self) -> BTreeMap<&Symbol, BTreeSet<&Number>> {
         use SchematicEntry as S;
 
         self.lines
@@ -167,14 +186,17 @@ impl Schematic {
             .sorted_by_key(|(sym, _num)| *sym)
             .group_by(|(sym, _num)| *sym)
             .into_iter()
-            .map(|(key, group)| (key, group.map(|(_, num)| num).collect::<HashSet<_>>()))
+            .map(|(key, group)| (key, group.map(|(_, num)| num).collect::<BTreeSet<_>>()))
             .collect()
     }
 
     pub fn part_nums(&self) -> impl Iterator<Item = usize> + '_ {
         self.adjacencies()
+            .into_values()
+            .flatten()
+            // a number that touches multiple symbols is still just one part number
+            .collect::<BTreeSet<_>>()
             .into_iter()
-            .flat_map(|(_, adj_nums)| adj_nums)
             .map(|Number { val, .. }| *val)
     }
 
@@ -187,9 +209,9 @@ impl Schematic {
     }
 }
 
-fn try_get_gear<'a, 'b>(
+pub fn try_get_gear<'a, 'b>(
     sym: &'a Symbol,
-    adj_nums: &HashSet<&'b Number>,
+    adj_nums: &BTreeSet<&'b Number>,
 ) -> Option<(&'a Symbol, [&'b Number; 2])> {
     if sym.val == '*' && adj_nums.len() == 2 {
         let mut it = adj_nums.iter();
@@ -199,16 +221,16 @@ fn try_get_gear<'a, 'b>(
     }
 }
 
-fn fst(input: &str) {
-    dbg!(Schematic::from_str(input)
+pub fn fst(input: &str) -> usize {
+    Schematic::from_str(input)
         .unwrap()
         .part_nums()
-        .sum::<usize>());
+        .sum::<usize>()
 }
 
-fn snd(input: &str) {
-    dbg!(Schematic::from_str(input)
+pub fn snd(input: &str) -> usize {
+    Schematic::from_str(input)
         .unwrap()
         .gear_ratios()
-        .sum::<usize>());
+        .sum::<usize>()
 }
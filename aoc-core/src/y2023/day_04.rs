@@ -0,0 +1,165 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::str::FromStr;
+
+use crate::bytes;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: usize,
+    pub winning_nums: Vec<usize>,
+    pub my_nums: Vec<usize>,
+}
+
+impl Card {
+    pub fn count_wins(&self) -> usize {
+        itertools::iproduct!(&self.winning_nums, &self.my_nums)
+            // note that using sets would be better here, but then we'd need to handle potential duplicates
+            .filter(|(x, y)| x == y)
+            .count()
+    }
+}
+
+impl FromStr for Card {
+    type Err = ();
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (head, nums) = line.split_once(':').ok_or(())?;
+        let id = head
+            .trim_start_matches("Card")
+            .trim()
+            .parse()
+            .map_err(|_| ())?;
+        let (win, mine) = nums.split_once('|').ok_or(())?;
+        let parse_ints = |s: &str| {
+            s.split_ascii_whitespace()
+                .map(|num| num.parse().map_err(|_| ()))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            id,
+            winning_nums: parse_ints(win)?,
+            my_nums: parse_ints(mine)?,
+        })
+    }
+}
+
+pub const fn points_from_count(count: usize) -> usize {
+    match count {
+        0 => 0,
+        n => 2_usize.pow((n - 1) as u32),
+    }
+}
+
+pub fn fst(input: &str) -> usize {
+    input
+        .lines()
+        .map(|s| s.parse::<Card>().unwrap())
+        .map(|card| points_from_count(card.count_wins()))
+        .sum::<usize>()
+}
+
+pub fn snd(input: &str) -> usize {
+    input
+        .lines()
+        .map(|s| s.parse::<Card>().unwrap())
+        // we fold down the collection scratch cards in order
+        .fold(
+            (0, VecDeque::from([1usize])),
+            |(total, mut multiplier_stack), card| {
+                // the top of the stack (front) is always the multiplier for the current card
+                // if the stack is empty it's 1 because we have 1 copy of each card at
+                // the beginning
+                let current_card_multiplier = multiplier_stack.pop_front().unwrap_or(1);
+                let current_wins = card.count_wins();
+                // we realize the cards on the stack up to the point of the current card's influence
+                if multiplier_stack.len() < current_wins {
+                    multiplier_stack.extend(vec![1; current_wins - multiplier_stack.len()]);
+                }
+                // we add the copies of the cards we just won by mutating the multipliers of the
+                // cards that are coming up
+                for multiplier in multiplier_stack.iter_mut().take(current_wins) {
+                    *multiplier += current_card_multiplier;
+                }
+                // we have as many copies of the current card as its multiplier says
+                // so we add that to the total
+                (total + current_card_multiplier, multiplier_stack)
+            },
+        )
+        .0
+}
+
+/// Most winning numbers a card can have in the `const` versions, which can't allocate
+pub const MAX_WINNING: usize = 32;
+/// How many cards ahead the `const` version of part 2 keeps track of won copies
+const LOOKAHEAD: usize = 64;
+
+/// [`Card::count_wins`] in `const` context, on a line of the input
+pub const fn count_wins_const(line: &[u8]) -> usize {
+    let Some(colon) = bytes::find(line, 0, b':') else {
+        panic!("not a card");
+    };
+    let mut winning = [0; MAX_WINNING];
+    let mut count = 0;
+    let mut idx = bytes::skip_spaces(line, colon + 1);
+    while line[idx] != b'|' {
+        if count == MAX_WINNING {
+            panic!("too many winning numbers");
+        }
+        (winning[count], idx) = bytes::parse_number(line, idx);
+        count += 1;
+        idx = bytes::skip_spaces(line, idx);
+    }
+
+    let mut wins = 0;
+    idx = bytes::skip_spaces(line, idx + 1);
+    while idx < line.len() {
+        let (num, next) = bytes::parse_number(line, idx);
+        // every pair counts, just like in `count_wins`
+        let mut i = 0;
+        while i < count {
+            if winning[i] == num {
+                wins += 1;
+            }
+            i += 1;
+        }
+        idx = bytes::skip_spaces(line, next);
+    }
+    wins
+}
+
+/// [`fst`] in `const` context
+pub const fn fst_const(input: &str) -> usize {
+    let input = input.as_bytes();
+    let (mut total, mut start) = (0, 0);
+    while start < input.len() {
+        let (line, next) = bytes::line_at(input, start);
+        total += points_from_count(count_wins_const(line));
+        start = next;
+    }
+    total
+}
+
+/// [`snd`] in `const` context: instead of a growing stack the copies won of the next
+/// cards are kept in a ring buffer
+pub const fn snd_const(input: &str) -> usize {
+    let input = input.as_bytes();
+    let mut won = [0; LOOKAHEAD];
+    let (mut total, mut card, mut start) = (0, 0, 0);
+    while start < input.len() {
+        let (line, next) = bytes::line_at(input, start);
+        let copies = 1 + won[card % LOOKAHEAD];
+        won[card % LOOKAHEAD] = 0;
+        let wins = count_wins_const(line);
+        if wins >= LOOKAHEAD {
+            panic!("card wins too many copies");
+        }
+        let mut ahead = 1;
+        while ahead <= wins {
+            won[(card + ahead) % LOOKAHEAD] += copies;
+            ahead += 1;
+        }
+        total += copies;
+        card += 1;
+        start = next;
+    }
+    total
+}
@@ -0,0 +1,211 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Range {
+    pub dest_start: usize,
+    pub source_start: usize,
+    pub len: usize,
+}
+
+impl FromStr for Range {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut it = s
+            .split_ascii_whitespace()
+            .map(|s| s.parse().map_err(|_| ()));
+        let mut next = || it.next().ok_or(())?;
+        Ok(Range {
+            dest_start: next()?,
+            source_start: next()?,
+            len: next()?,
+        })
+    }
+}
+
+impl Range {
+    pub fn source_end(&self) -> usize {
+        self.source_start + self.len
+    }
+
+    pub fn dest_end(&self) -> usize {
+        self.dest_start + self.len
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum RangeOutput {
+    InRange(usize),
+    OutRange(usize),
+}
+
+impl RangeOutput {
+    pub fn inner(self) -> usize {
+        match self {
+            RangeOutput::InRange(x) => x,
+            RangeOutput::OutRange(x) => x,
+        }
+    }
+}
+
+impl Range {
+    /// The part of this range whose destinations are within `start..end`, if any
+    pub fn restrict(&self, start: usize, end: usize) -> Option<Self> {
+        let (from, to) = (start.max(self.dest_start), end.min(self.dest_end()));
+        (from < to).then(|| Range {
+            dest_start: from,
+            source_start: self.source_start + (from - self.dest_start),
+            len: to - from,
+        })
+    }
+
+    /// Compose the function induced by this range with `source`'s in the sense of
+    /// self(source(x)), on the part of `source` whose destinations this range covers
+    pub fn compose(&self, source: &Self) -> Option<Self> {
+        let inner = source.restrict(self.source_start, self.source_end())?;
+        Some(Range {
+            dest_start: self.dest_start + (inner.dest_start - self.source_start),
+            ..inner
+        })
+    }
+
+    /// Get the destination value for a given source value
+    pub fn dest_for(&self, source: usize) -> RangeOutput {
+        // the source range is `source_start..source_end()` - the end is exclusive
+        match source.checked_sub(self.source_start) {
+            Some(dist) if dist < self.len => RangeOutput::InRange(self.dest_start + dist),
+            _ => RangeOutput::OutRange(source),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map(pub Vec<Range>);
+
+impl Map {
+    pub fn dest_for(&self, source: usize) -> usize {
+        for map in &self.0 {
+            if let RangeOutput::InRange(res) = map.dest_for(source) {
+                return res;
+            }
+        }
+        source
+    }
+
+    /// Compose the function of this map with `source`'s in the sense of self(source(x)):
+    /// the ranges of the result cover the sources of `source`'s ranges. Like
+    /// [`dest_for`](Self::dest_for) the first range containing a value decides and the
+    /// values no range covers stay where they are.
+    pub fn compose(&self, source: &Self) -> Self {
+        let mut unmapped = source.0.clone();
+        let mut composed = Vec::new();
+        for range in &self.0 {
+            let mut rest = Vec::new();
+            for piece in unmapped {
+                composed.extend(range.compose(&piece));
+                // the parts of the piece ending up left and right of the range
+                rest.extend(piece.restrict(piece.dest_start, range.source_start));
+                rest.extend(piece.restrict(range.source_end(), piece.dest_end()));
+            }
+            unmapped = rest;
+        }
+        composed.extend(unmapped);
+        Self(composed)
+    }
+}
+
+/// The parsed puzzle input: the seeds and the maps leading from seeds to locations in
+/// order, each with its name (e.g. `seed-to-soil`)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<(String, Map)>,
+}
+
+impl FromStr for Almanac {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let seeds = input
+            .lines()
+            .next()
+            .ok_or(())?
+            .trim_start_matches("seeds:")
+            .split_ascii_whitespace()
+            .map(|seed| seed.parse::<usize>().map_err(|_| ()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let maps = input
+            .split("\n\n")
+            .skip(1)
+            .map(|block| {
+                let name = block
+                    .lines()
+                    .next()
+                    .ok_or(())?
+                    .trim_end_matches(" map:")
+                    .to_string();
+                let map = Map(block
+                    .lines()
+                    .skip(1)
+                    .map(|line| line.parse::<Range>())
+                    .collect::<Result<_, _>>()?);
+                Ok((name, map))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { seeds, maps })
+    }
+}
+
+impl Almanac {
+    /// Follow a seed through all the maps, returning the value after each map
+    pub fn trace(&self, seed: usize) -> Vec<usize> {
+        self.maps
+            .iter()
+            .scan(seed, |current, (_, map)| {
+                *current = map.dest_for(*current);
+                Some(*current)
+            })
+            .collect()
+    }
+
+    pub fn location(&self, seed: usize) -> usize {
+        self.trace(seed).last().copied().unwrap_or(seed)
+    }
+}
+
+pub fn fst(input: &str) -> usize {
+    let almanac = input.parse::<Almanac>().unwrap();
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| almanac.location(seed))
+        .min()
+        .unwrap()
+}
+
+pub fn snd(input: &str) -> usize {
+    let almanac = input.parse::<Almanac>().unwrap();
+    // the seeds come in pairs of the start and length of a range, as a map its sources
+    // don't matter - only where they lead to
+    let seeds = Map(almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|pair| Range {
+            dest_start: pair[0],
+            source_start: 0,
+            len: pair[1],
+        })
+        .collect());
+    almanac
+        .maps
+        .iter()
+        .fold(seeds, |current, (_, map)| map.compose(&current))
+        .0
+        .iter()
+        .filter(|range| range.len != 0)
+        .map(|range| range.dest_start)
+        .min()
+        .unwrap()
+}
@@ -0,0 +1,7 @@
+//! Advent of Code 2023.
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
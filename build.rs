@@ -0,0 +1,153 @@
+//! With the `embed` feature the puzzle inputs get decrypted and compressed at build time
+//! so they can be compiled into the binaries (see `src/input/mod.rs`).
+//!
+//! The examples of part 1 are copied out of the saved examples for `aoc::input`'s
+//! `TEST_INPUT`s, so that they don't have to be kept in sync by hand.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+#[allow(dead_code)]
+#[path = "src/config.rs"]
+mod config;
+#[allow(dead_code)]
+#[path = "src/crypt.rs"]
+mod crypt;
+#[allow(dead_code)]
+#[path = "src/lz.rs"]
+mod lz;
+
+/// Same as `profile::PROFILE_ENV` (the profile module can't be included here)
+const PROFILE_ENV: &str = "AOC_PROFILE";
+/// The days `aoc::precomputed` solves at compile time, the only ones whose inputs are
+/// needed as plain text
+const PRECOMPUTED: &[(usize, usize)] = &[(2023, 1), (2023, 4)];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", config::FILE);
+    println!("cargo:rerun-if-changed={}", config::LOCAL_FILE);
+    println!("cargo:rerun-if-env-changed={}", crypt::KEY_ENV);
+    println!("cargo:rerun-if-env-changed={PROFILE_ENV}");
+    let config = config::Config::load().unwrap_or_else(|err| panic!("{err}"));
+    println!("cargo:rerun-if-changed={}", config.session_file().display());
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    #[cfg(feature = "capi")]
+    generate_header(&out_dir);
+    write_examples(&config.puzzles_dir(), &out_dir);
+    if env::var_os("CARGO_FEATURE_EMBED").is_none() {
+        return;
+    }
+
+    let key = crypt::Key::load()
+        .unwrap_or_else(|err| panic!("the `embed` feature needs the input key: {err}"));
+    let inputs = config.inputs_dir();
+    println!("cargo:rerun-if-changed={}", inputs.display());
+    // the inputs of the profile we're building for that get solved at compile time are
+    // also available as plain text
+    let build_profile = env::var(PROFILE_ENV).unwrap_or_else(|_| "default".to_string());
+    let mut entries = vec![];
+    let mut plain = vec![];
+    let mut profiles = fs::read_dir(&inputs)
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    profiles.sort();
+    for profile_dir in profiles {
+        let profile = profile_dir.file_name().unwrap().to_str().unwrap();
+        let mut years = fs::read_dir(&profile_dir)
+            .unwrap()
+            .filter_map(|entry| entry.unwrap().file_name().to_str()?.parse::<usize>().ok())
+            .collect::<Vec<_>>();
+        years.sort();
+        for year in years {
+            for day in 1..=25 {
+                let path = profile_dir
+                    .join(year.to_string())
+                    .join(format!("day_{day:02}.txt.enc"));
+                let Ok(encrypted) = fs::read(&path) else {
+                    continue;
+                };
+                let plaintext = crypt::decrypt(&key, &encrypted)
+                    .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+                if profile == build_profile {
+                    let file = format!("{year}_day_{day:02}.txt");
+                    if PRECOMPUTED.contains(&(year, day)) {
+                        fs::write(out_dir.join(&file), &plaintext).unwrap();
+                        plain.push(format!(
+                            "({year}, {day}) => \
+                             Some(include_str!(concat!(env!(\"OUT_DIR\"), \"/{file}\"))),"
+                        ));
+                    } else {
+                        // in case an earlier build needed it
+                        let _ = fs::remove_file(out_dir.join(&file));
+                    }
+                }
+                let file = format!("{profile}_{year}_day_{day:02}.lz");
+                fs::write(out_dir.join(&file), lz::compress(&plaintext)).unwrap();
+                entries.push(format!(
+                    "({profile:?}, {year}, {day}, \
+                     include_bytes!(concat!(env!(\"OUT_DIR\"), \"/{file}\"))),"
+                ));
+            }
+        }
+    }
+    fs::write(
+        out_dir.join("embedded.rs"),
+        format!(
+            "pub static INPUTS: &[(&str, usize, usize, &[u8])] = &[\n{}\n];\n\n\
+             pub const fn plain(year: usize, day: usize) -> Option<&'static str> {{\n\
+             match (year, day) {{\n{}\n_ => None,\n}}\n}}\n",
+            entries.join("\n"),
+            plain.join("\n")
+        ),
+    )
+    .unwrap();
+}
+
+/// Write the example of part 1 of every day with saved examples to
+/// `example_<year>_day_XX.txt` (see `aoc::puzzle` for the format)
+fn write_examples(puzzles: &Path, out_dir: &Path) {
+    println!("cargo:rerun-if-changed={}", puzzles.display());
+    for year_dir in fs::read_dir(puzzles).into_iter().flatten().flatten() {
+        let year = year_dir.file_name().into_string().unwrap();
+        for day_dir in fs::read_dir(year_dir.path()).unwrap().flatten() {
+            let Ok(examples) = fs::read_to_string(day_dir.path().join("examples.txt")) else {
+                continue;
+            };
+            let example = examples
+                .lines()
+                .skip_while(|line| !line.starts_with("== part 1: "))
+                .skip(1)
+                .take_while(|line| !line.starts_with("== part "))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let day = day_dir.file_name().into_string().unwrap();
+            fs::write(
+                out_dir.join(format!("example_{year}_{day}.txt")),
+                example.trim_end_matches('\n'),
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Generate the C header of the shared library from `src/capi.rs`. It goes into
+/// `OUT_DIR` like everything else the build writes; the tests check that the copy in
+/// `include/` is up to date (see `src/capi.rs`).
+#[cfg(feature = "capi")]
+fn generate_header(out_dir: &Path) {
+    println!("cargo:rerun-if-changed=src/capi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    let config = cbindgen::Config::from_file("cbindgen.toml").unwrap();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src("src/capi.rs")
+        .generate()
+        .expect("failed to generate the C header")
+        .write_to_file(out_dir.join("aoc.h"));
+}
@@ -0,0 +1,70 @@
+//! An example solution plugin (see `aoc::plugin`) with experimental versions of some days.
+//!
+//! Usage: `cargo build --example plugin` and copy `libplugin.so` from
+//! `target/debug/examples` into the plugins directory.
+
+/// Day 1, looking for the first and last digit from both ends of each line instead of
+/// parsing all of them
+mod day_01 {
+    use aoc::y2023::day_01::digit_at;
+
+    fn calibration_sum(input: &str, words: bool) -> usize {
+        input
+            .lines()
+            .map(|line| {
+                let line = line.as_bytes();
+                let first = (0..line.len())
+                    .find_map(|idx| digit_at(line, idx, words))
+                    .unwrap();
+                let last = (0..line.len())
+                    .rev()
+                    .find_map(|idx| digit_at(line, idx, words))
+                    .unwrap();
+                10 * first + last
+            })
+            .sum()
+    }
+
+    pub fn fst(input: &str) -> usize {
+        calibration_sum(input, false)
+    }
+
+    pub fn snd(input: &str) -> usize {
+        calibration_sum(input, true)
+    }
+}
+
+/// Day 4 part 1, with the winning numbers of a card in a bitset
+mod day_04 {
+    use aoc::y2023::day_04::{points_from_count, Card};
+
+    fn bit(num: usize) -> u128 {
+        assert!(num < 128, "the bitset only fits numbers below 128");
+        1 << num
+    }
+
+    pub fn fst(input: &str) -> usize {
+        input
+            .lines()
+            .map(|line| line.parse::<Card>().unwrap())
+            .map(|card| {
+                let winning = card.winning_nums.iter().fold(0, |set, &num| set | bit(num));
+                let wins = card
+                    .my_nums
+                    .iter()
+                    .filter(|&&num| winning & bit(num) != 0)
+                    .count();
+                points_from_count(wins)
+            })
+            .sum()
+    }
+}
+
+aoc::export_plugin!(
+    "example",
+    [
+        (2023, 1, 1, day_01::fst),
+        (2023, 1, 2, day_01::snd),
+        (2023, 4, 1, day_04::fst),
+    ]
+);
@@ -0,0 +1,93 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated from src/capi.rs with the `capi` feature (see there for updating it), don't edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Size of an output buffer that's big enough for any answer and the terminating NUL
+ */
+#define AOC_ANSWER_LEN 32
+
+/**
+ * What became of a call
+ */
+enum AocStatus
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : int32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * The answer was written to the output buffer
+   */
+  AOC_STATUS_OK = 0,
+  /**
+   * There's no solver for the year, day and part
+   */
+  AOC_STATUS_UNIMPLEMENTED = 1,
+  /**
+   * The input or the output buffer is a null pointer (and not empty)
+   */
+  AOC_STATUS_NULL_POINTER = 2,
+  /**
+   * The input isn't valid UTF-8
+   */
+  AOC_STATUS_INVALID_UTF8 = 3,
+  /**
+   * The input breaks the assumptions of the day's solutions
+   */
+  AOC_STATUS_INVALID_INPUT = 4,
+  /**
+   * The solver panicked
+   */
+  AOC_STATUS_PANICKED = 5,
+  /**
+   * The answer (with its NUL) doesn't fit in the output buffer
+   */
+  AOC_STATUS_BUFFER_TOO_SMALL = 6,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum AocStatus AocStatus;
+#else
+typedef int32_t AocStatus;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Solve a part (1 or 2) of a day on an input of `len` bytes, writing the answer as a
+ * NUL-terminated decimal string to `out_buf`, which has room for `out_len` bytes.
+ *
+ * # Safety
+ *
+ * `input_ptr` has to point to `len` readable bytes and `out_buf` to `out_len` writable
+ * ones, unless the respective length is 0.
+ */
+AocStatus aoc_solve(uint32_t year,
+                    uint32_t day,
+                    uint32_t part,
+                    const uint8_t *input_ptr,
+                    size_t len,
+                    char *out_buf,
+                    size_t out_len);
+
+/**
+ * A static, NUL-terminated description of a status (taken as a plain integer so that
+ * unknown values are fine)
+ */
+const char *aoc_status_message(int32_t status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
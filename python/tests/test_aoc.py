@@ -0,0 +1,118 @@
+"""Checks the Python bindings against the examples from the puzzle descriptions.
+
+Needs the extension module to be importable, see `src/python.rs` for how to build it.
+"""
+
+import unittest
+
+import aoc
+
+GAMES = """\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+"""
+
+CARDS = """\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+"""
+
+# inputs each day rejects
+MALFORMED = {
+    (2023, 1): ["no digits here", "1\n\n2"],
+    (2023, 2): [
+        "Game one: 3 blue",
+        "Game 1: 3 purple",
+        "Game 1: 99999999999999999999 red",
+    ],
+    (2023, 3): ["467..114..\n...*...\u00e9..", "99999999999999999999*"],
+    (2023, 4): ["Card 1: 41 48 | x", "Card 2: 1 | 1"],
+    (2023, 5): [
+        "seeds: 1",
+        "seeds: 1 2",
+        "seeds: 18446744073709551615 2\n\nseed-to-location map:\n0 0 1",
+    ],
+}
+
+
+class Solve(unittest.TestCase):
+    def test_examples(self):
+        checked = 0
+        for year, day in aoc.days():
+            for example in aoc.examples(year, day):
+                part = example["part"]
+                with self.subTest(year=year, day=day, part=part):
+                    try:
+                        answer = aoc.solve(year, day, part, example["input"])
+                    except ValueError as err:
+                        if "isn't implemented" in str(err):
+                            continue
+                        raise
+                    self.assertEqual(answer, example["answer"])
+                    checked += 1
+        self.assertGreater(checked, 0)
+
+    def test_unimplemented(self):
+        with self.assertRaises(ValueError):
+            aoc.solve(2023, 25, 1, "")
+
+    def test_invalid_input(self):
+        with self.assertRaisesRegex(ValueError, "invalid input"):
+            aoc.solve(2023, 2, 1, "Game one: 3 blue\n")
+
+    def test_malformed(self):
+        # a panic would be a `PanicException`, which isn't a `ValueError`
+        for (year, day), texts in MALFORMED.items():
+            for part in [1, 2]:
+                for text in texts:
+                    with self.subTest(year=year, day=day, part=part, text=text):
+                        with self.assertRaises(ValueError):
+                            aoc.solve(year, day, part, text)
+
+
+class Parse(unittest.TestCase):
+    def test_malformed(self):
+        for (year, day), texts in MALFORMED.items():
+            for text in texts:
+                with self.subTest(year=year, day=day, text=text):
+                    with self.assertRaises(ValueError):
+                        aoc.parse(year, day, text)
+
+    def test_digits(self):
+        self.assertEqual(aoc.parse(2023, 1, "two1nine\nabc3\n"), [[2, 1, 9], [3]])
+
+    def test_games(self):
+        games = aoc.parse(2023, 2, GAMES)
+        self.assertEqual([game["id"] for game in games], [1, 2])
+        self.assertEqual(
+            games[0]["draws"],
+            [{"blue": 3, "red": 4}, {"red": 1, "green": 2, "blue": 6}, {"green": 2}],
+        )
+
+    def test_schematic(self):
+        [example] = [e for e in aoc.examples(2023, 3) if e["part"] == 1]
+        schematic = aoc.parse(2023, 3, example["input"])
+        self.assertEqual(len(schematic["numbers"]), 10)
+        self.assertEqual(schematic["symbols"][0], {"symbol": "*", "line": 1, "idx": 3})
+        first = schematic["adjacencies"][0]
+        self.assertEqual(first["symbol"]["symbol"], "*")
+        self.assertEqual([n["value"] for n in first["numbers"]], [467, 35])
+
+    def test_cards(self):
+        cards = aoc.parse(2023, 4, CARDS)
+        self.assertEqual([card["wins"] for card in cards], [4, 2])
+        self.assertEqual(cards[1]["winning"], [13, 32, 20, 16, 61])
+
+    def test_almanac(self):
+        [example] = [e for e in aoc.examples(2023, 5) if e["part"] == 1]
+        almanac = aoc.parse(2023, 5, example["input"])
+        self.assertEqual(almanac["seeds"], [79, 14, 55, 13])
+        self.assertEqual(almanac["maps"][0]["name"], "seed-to-soil")
+        self.assertEqual(
+            almanac["maps"][0]["ranges"][0],
+            {"dest_start": 50, "source_start": 98, "len": 2},
+        )
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,205 @@
+//! Known answers and the submission history of a [`Profile`].
+//!
+//! Both are plain text files in the profile's directory for the year: `answers.txt` holds one
+//! `DAY PART ANSWER` line per solved part and `submissions.txt` one
+//! `TIMESTAMP DAY PART ANSWER VERDICT` line for every answer we've submitted.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{profile::Profile, progress::Progress};
+
+/// The correct answers, keyed by `(day, part)`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(pub BTreeMap<(usize, usize), usize>);
+
+const ANSWERS_FILE: &str = "answers.txt";
+const SUBMISSIONS_FILE: &str = "submissions.txt";
+
+fn answers_path(profile: &Profile, year: usize) -> PathBuf {
+    profile.year_dir(year).join(ANSWERS_FILE)
+}
+
+fn submissions_path(profile: &Profile, year: usize) -> PathBuf {
+    profile.year_dir(year).join(SUBMISSIONS_FILE)
+}
+
+/// The whitespace separated fields of a line, `None` unless there are exactly `N`
+pub(crate) fn fields<const N: usize>(line: &str) -> Option<[&str; N]> {
+    line.split_ascii_whitespace()
+        .collect::<Vec<_>>()
+        .try_into()
+        .ok()
+}
+
+/// The entries of one of a profile's files, one per non-blank line. Malformed lines
+/// (from editing the file by hand, say) are reported and skipped rather than taking
+/// the rest of the file down with them.
+pub(crate) fn entries<'a, T>(
+    content: &'a str,
+    file: &'a str,
+    parse: impl Fn(&str) -> Option<T> + 'a,
+) -> impl Iterator<Item = T> + 'a {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(move |(idx, line)| {
+            let entry = parse(line);
+            if entry.is_none() {
+                eprintln!("{file}:{}: skipping malformed line {line:?}", idx + 1);
+            }
+            entry
+        })
+}
+
+impl Answers {
+    pub fn load(profile: &Profile, year: usize) -> Self {
+        fs::read_to_string(answers_path(profile, year))
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Parse the contents of `answers.txt`, skipping malformed lines
+    pub fn parse(content: &str) -> Self {
+        Self(
+            entries(content, ANSWERS_FILE, |line| {
+                let [day, part, answer] = fields(line)?.map(str::parse::<usize>);
+                Some(((day.ok()?, part.ok()?), answer.ok()?))
+            })
+            .collect(),
+        )
+    }
+
+    pub fn save(&self, profile: &Profile, year: usize) -> io::Result<()> {
+        fs::create_dir_all(profile.year_dir(year))?;
+        fs::write(
+            answers_path(profile, year),
+            self.0
+                .iter()
+                .map(|((day, part), answer)| format!("{day} {part} {answer}\n"))
+                .collect::<String>(),
+        )
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<usize> {
+        self.0.get(&(day, part)).copied()
+    }
+}
+
+/// What the puzzle page said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl FromStr for Verdict {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch
+    pub time: u64,
+    pub day: usize,
+    pub part: usize,
+    pub answer: usize,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    pub fn now(day: usize, part: usize, answer: usize, verdict: Verdict) -> Self {
+        Self {
+            time: now(),
+            day,
+            part,
+            answer,
+            verdict,
+        }
+    }
+}
+
+/// A `TIMESTAMP DAY PART ANSWER VERDICT` line of `submissions.txt`
+impl FromStr for Submission {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [time, day, part, answer, verdict] = fields(s).ok_or(())?;
+        Ok(Self {
+            time: time.parse().map_err(|_| ())?,
+            day: day.parse().map_err(|_| ())?,
+            part: part.parse().map_err(|_| ())?,
+            answer: answer.parse().map_err(|_| ())?,
+            verdict: verdict.parse()?,
+        })
+    }
+}
+
+/// Seconds since the unix epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// All submissions of a profile for a year in the order they were made
+pub fn submissions(profile: &Profile, year: usize) -> Vec<Submission> {
+    let Ok(content) = fs::read_to_string(submissions_path(profile, year)) else {
+        return vec![];
+    };
+    entries(&content, SUBMISSIONS_FILE, |line| line.parse().ok()).collect()
+}
+
+/// Record a submission - if it was correct its answer also becomes the known answer.
+/// Also keeps the [`Progress`] up to date.
+pub fn record(profile: &Profile, year: usize, submission: &Submission) -> io::Result<()> {
+    fs::create_dir_all(profile.year_dir(year))?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(submissions_path(profile, year))?;
+    writeln!(
+        file,
+        "{} {} {} {} {}",
+        submission.time, submission.day, submission.part, submission.answer, submission.verdict
+    )?;
+    if submission.verdict == Verdict::Correct {
+        let mut answers = Answers::load(profile, year);
+        answers
+            .0
+            .insert((submission.day, submission.part), submission.answer);
+        answers.save(profile, year)?;
+    }
+    // loading picks up the submission we just appended
+    Progress::load(profile, year).save(profile, year)
+}
@@ -0,0 +1,285 @@
+//! Runs the solutions against the stored inputs.
+//!
+//! Usage:
+//! * `aoc run DAY [--profile NAME|all] [--input PATH]` - solve a day and check the
+//!   answers against the known ones. The input is taken from `PATH` (`-` for stdin) if
+//!   given, and otherwise the stored inputs of the profiles are used.
+//! * `aoc bench DAY [..]` - like `run`, but rerun every part for a while and report its
+//!   median time
+//! * `aoc verify DAY [..]` - like `run`, but also compare every part against its oracle
+//!   on generated inputs
+//! * `aoc record DAY PART ANSWER VERDICT [--profile NAME]` - record a submission, where
+//!   the verdict is one of `correct`, `too-high`, `too-low` or `wrong`
+//! * `aoc start DAY [PART]` - note that we've started working on a part (part 1 by
+//!   default, part 2 starts by itself once part 1 is solved)
+//! * `aoc stats [--profile NAME]` - show the calendar of stars and our solve times
+//! * `aoc profiles` - list all profiles
+//! * `aoc plugins` - list the loaded [plugins](aoc::plugin) and what they solve
+//!
+//! Solving, benchmarking and verifying also check the variants of the plugins for the
+//! day, which have to agree with the known answers or, without one, with our solver.
+//! Without `--profile` the current profile (`AOC_PROFILE`, `default` if unset) is used.
+//! Everything works on the latest year with any solutions unless `--year YEAR` is given.
+//!
+//! The defaults come from the [config](aoc::config), and these flags override it:
+//! `--format text|csv` for how results are printed, `--timeout SECS` for how long a part
+//! may run, `--iterations N` for the most runs to benchmark a part with and `--seeds N`
+//! for the number of generated inputs to verify on.
+
+use std::time::Duration;
+
+use aoc::{
+    answers::{self, Answers, Submission},
+    check::{self, Action, Report},
+    config::{self, Config, Format},
+    days,
+    input::Source,
+    leaderboard::csv_field,
+    plugin::Plugins,
+    profile::Profile,
+    progress::Progress,
+    validate::{self, Severity},
+};
+
+const USAGE: &str = "usage: aoc (run DAY | bench DAY | verify DAY \
+    | record DAY PART ANSWER VERDICT | start DAY [PART] | stats | profiles | plugins) \
+    [--year YEAR] [--profile NAME|all] [--input PATH] [--format text|csv] [--timeout SECS] \
+    [--iterations N] [--seeds N]";
+
+fn main() {
+    let mut config = Config::get().clone();
+    let mut profiles = vec![Profile::current()];
+    let mut source = None;
+    let mut args = vec![];
+    let mut it = std::env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--year" => config.year = Some(it.next().expect(USAGE).parse().expect(USAGE)),
+            "--profile" => {
+                profiles = match it.next().expect(USAGE).as_str() {
+                    "all" => Profile::all(),
+                    name => vec![Profile::new(name)],
+                }
+            }
+            "--input" => source = Some(Source::from_arg(&it.next().expect(USAGE))),
+            "--format" => config.format = Some(it.next().expect(USAGE).parse().expect(USAGE)),
+            "--timeout" => {
+                let timeout = it.next().expect(USAGE).parse().expect(USAGE);
+                assert!(config::valid_seconds(timeout), "invalid timeout {timeout}");
+                config.timeout = Some(timeout);
+            }
+            "--iterations" => {
+                config.bench.iterations = Some(it.next().expect(USAGE).parse().expect(USAGE))
+            }
+            "--seeds" => config.verify.seeds = Some(it.next().expect(USAGE).parse().expect(USAGE)),
+            _ => args.push(arg),
+        }
+    }
+    let year = config.year.unwrap_or_else(days::latest_year);
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    match args.as_slice() {
+        [command @ ("run" | "bench" | "verify"), day] => {
+            let day = day.parse().unwrap();
+            if days::get(year, day).is_none() {
+                panic!("{year} day {day} isn't implemented");
+            }
+            let plugins = load_plugins(&config);
+            let runner = Runner {
+                action: match *command {
+                    "run" => Action::Run,
+                    "bench" => Action::Bench,
+                    _ => Action::Verify,
+                },
+                config: &config,
+                plugins: &plugins,
+                year,
+                day,
+            };
+            if config.format() == Format::Csv {
+                println!("year,day,part,input,status,answer,time_ns");
+            }
+            match source {
+                Some(source) => runner.run(&source, None),
+                None => {
+                    for profile in &profiles {
+                        runner.run(&Source::Stored(profile.clone()), Some(profile));
+                    }
+                }
+            }
+        }
+        ["record", day, part, answer, verdict] => {
+            let [profile] = profiles.as_slice() else {
+                panic!("submissions can only be recorded for a single profile");
+            };
+            let submission = Submission::now(
+                day.parse().unwrap(),
+                part.parse().unwrap(),
+                answer.parse().unwrap(),
+                verdict
+                    .parse()
+                    .unwrap_or_else(|()| panic!("unknown verdict {verdict}")),
+            );
+            answers::record(profile, year, &submission).unwrap();
+        }
+        ["start", day, part @ ..] => {
+            let [profile] = profiles.as_slice() else {
+                panic!("progress can only be recorded for a single profile");
+            };
+            let part = match part {
+                [] => 1,
+                [part] => part.parse().unwrap(),
+                _ => panic!("{USAGE}"),
+            };
+            let mut progress = Progress::load(profile, year);
+            progress.start(day.parse().unwrap(), part, answers::now());
+            progress.save(profile, year).unwrap();
+        }
+        ["stats"] => {
+            for profile in &profiles {
+                if profiles.len() > 1 {
+                    println!("[{profile}]");
+                }
+                print!("{}", Progress::load(profile, year).render());
+            }
+        }
+        ["profiles"] => {
+            for profile in Profile::all() {
+                println!("{profile}");
+            }
+        }
+        ["plugins"] => {
+            for plugin in &load_plugins(&config).plugins {
+                println!("{} ({})", plugin.name, plugin.path.display());
+                for variant in &plugin.variants {
+                    println!(
+                        "  {} day {} part {}",
+                        variant.year, variant.day, variant.part
+                    );
+                }
+            }
+        }
+        _ => panic!("{USAGE}"),
+    }
+}
+
+/// Load the plugins, complaining about the libraries that aren't any
+fn load_plugins(config: &Config) -> Plugins {
+    // SAFETY: whatever is in the plugins directory is trusted like the rest of the project
+    let plugins = unsafe { Plugins::load(&config.plugins_dir()) };
+    for (path, err) in &plugins.errors {
+        eprintln!("can't load plugin {}: {err}", path.display());
+    }
+    plugins
+}
+
+/// Runs, benchmarks or verifies one day and prints the results
+struct Runner<'a> {
+    action: Action,
+    config: &'a Config,
+    plugins: &'a Plugins,
+    year: usize,
+    day: usize,
+}
+
+impl Runner<'_> {
+    /// Print a message about an input that isn't a result; in CSV it goes to stderr
+    /// so the output stays parseable
+    fn note(&self, label: &str, message: impl std::fmt::Display) {
+        let line = format!("{} day {} [{label}]: {message}", self.year, self.day);
+        match self.config.format() {
+            Format::Text => println!("{line}"),
+            Format::Csv => eprintln!("{line}"),
+        }
+    }
+
+    fn print(&self, label: &str, report: &Report) {
+        let Runner { year, day, .. } = *self;
+        let part = report.part;
+        match self.config.format() {
+            Format::Text => {
+                let time = match (self.action, report.time) {
+                    (Action::Bench, Some(time)) => {
+                        format!(" in {}", check::format_duration(time))
+                    }
+                    _ => String::new(),
+                };
+                println!(
+                    "{year} day {day} part {part} [{label}]: {}{time}",
+                    report.status
+                );
+            }
+            Format::Csv => println!(
+                "{year},{day},{part},{},{},{},{}",
+                csv_field(label),
+                report.status.kind(),
+                report
+                    .status
+                    .answer()
+                    .map(|a| a.to_string())
+                    .unwrap_or_default(),
+                report
+                    .time
+                    .as_ref()
+                    .map(Duration::as_nanos)
+                    .map(|t| t.to_string())
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+
+    /// Solve the day on the input from some source, checking the answers against the
+    /// known ones of a profile
+    fn run(&self, source: &Source, profile: Option<&Profile>) {
+        let label = match source {
+            Source::Stdin => "stdin".to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::Stored(profile) => profile.to_string(),
+        };
+        match source.read(self.year, self.day) {
+            Ok(input) => self.run_input(&input, &label, profile),
+            Err(err) => self.note(&label, format_args!("can't load input: {err}")),
+        }
+    }
+
+    fn run_input(&self, input: &str, label: &str, profile: Option<&Profile>) {
+        let violations = validate::schema(self.year, self.day)
+            .map(|schema| schema.validate(input))
+            .unwrap_or_default();
+        for violation in &violations {
+            self.note(label, violation);
+        }
+        if violations.iter().any(|v| v.severity == Severity::Error) {
+            return;
+        }
+
+        let answers = profile
+            .map(|profile| Answers::load(profile, self.year))
+            .unwrap_or_default();
+        let reports = check::day(
+            self.year,
+            self.day,
+            input,
+            &answers,
+            self.action,
+            self.config,
+        );
+        for report in &reports {
+            self.print(label, report);
+        }
+        for variant in self.plugins.variants(self.year, self.day) {
+            let reference = reports
+                .iter()
+                .find(|report| report.part == variant.part)
+                .and_then(|report| report.status.answer());
+            let report = check::variant(
+                variant,
+                input,
+                &answers,
+                reference,
+                self.action,
+                self.config,
+            );
+            self.print(&format!("{label} via {}", variant.plugin), &report);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Manages the encrypted puzzle inputs.
+//!
+//! Usage:
+//! * `crypt keygen` - create a new key file
+//! * `crypt encrypt DAY [FILE]` - encrypt an input (read from stdin without a file)
+//! * `crypt decrypt DAY` - print a decrypted input
+//! * `crypt rekey` - re-encrypt all inputs with a freshly generated key (the key file's,
+//!   so `AOC_KEY` mustn't be set). The new key is staged next to the key file (in
+//!   `.aoc-key.new`) until all inputs are re-encrypted, so if a rekey gets interrupted
+//!   running it again finishes the job with the staged key.
+//!
+//! `encrypt` and `decrypt` work on the current profile unless another one is given via
+//! `--profile NAME`, and on the latest year unless another one is given via `--year YEAR`.
+
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::Path,
+};
+
+use aoc::{
+    crypt::{self, Key},
+    days, input,
+    profile::Profile,
+};
+
+const USAGE: &str = "usage: crypt (keygen | encrypt DAY [FILE] | decrypt DAY | rekey) \
+    [--profile NAME] [--year YEAR]";
+
+fn main() {
+    let mut profile = Profile::current();
+    let mut year = days::default_year();
+    let mut args = vec![];
+    let mut it = std::env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--profile" => profile = Profile::new(&it.next().expect(USAGE)),
+            "--year" => year = it.next().expect(USAGE).parse().expect(USAGE),
+            _ => args.push(arg),
+        }
+    }
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    match args.as_slice() {
+        ["keygen"] => keygen(),
+        ["encrypt", day, rest @ ..] => {
+            let plaintext = match rest {
+                [file] => fs::read(file).unwrap(),
+                [] => {
+                    let mut buf = vec![];
+                    io::stdin().read_to_end(&mut buf).unwrap();
+                    buf
+                }
+                _ => panic!("{USAGE}"),
+            };
+            encrypt(&profile, year, day.parse().unwrap(), &plaintext);
+        }
+        ["decrypt", day] => {
+            let day = day.parse().unwrap();
+            let input = input::try_load(&profile, year, day).unwrap_or_else(|err| panic!("{err}"));
+            io::stdout().write_all(input.as_bytes()).unwrap();
+        }
+        ["rekey"] => rekey(),
+        _ => panic!("{USAGE}"),
+    }
+}
+
+fn keygen() {
+    let path = crypt::key_file();
+    assert!(
+        !path.exists(),
+        "{} already exists - use `crypt rekey` to replace it",
+        path.display()
+    );
+    fs::write(&path, Key::generate().to_hex()).unwrap();
+    println!("new key written to {}", path.display());
+}
+
+fn encrypt(profile: &Profile, year: usize, day: usize, plaintext: &[u8]) {
+    let key = Key::load().unwrap_or_else(|err| panic!("{err}"));
+    fs::create_dir_all(profile.year_dir(year)).unwrap();
+    let path = input::path(profile, year, day);
+    fs::write(&path, crypt::encrypt(&key, plaintext)).unwrap();
+    println!(
+        "encrypted input of {profile} for {year} day {day} written to {}",
+        path.display()
+    );
+}
+
+fn rekey() {
+    assert!(
+        std::env::var(crypt::KEY_ENV).is_err(),
+        "{} is set and would be stale after rekeying - unset it to rekey with the key file",
+        crypt::KEY_ENV
+    );
+    let old = Key::load().unwrap_or_else(|err| panic!("{err}"));
+    let key_file = crypt::key_file();
+    let new_key_file = key_file.with_extension("new");
+    // a staged key means an earlier rekey was interrupted, possibly after some of the
+    // inputs were already re-encrypted with it
+    let staged = match fs::read_to_string(&new_key_file) {
+        Ok(hex) => Some(
+            Key::from_hex(&hex).unwrap_or_else(|err| panic!("{}: {err}", new_key_file.display())),
+        ),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => panic!("{}: {err}", new_key_file.display()),
+    };
+    if staged.is_some() {
+        println!(
+            "resuming an interrupted rekey with the key staged in {}",
+            new_key_file.display()
+        );
+    }
+    let new = staged.clone().unwrap_or_else(Key::generate);
+    // decrypt everything first so that we don't end up with a mix of keys if one of
+    // the inputs can't be decrypted
+    let inputs = Profile::all()
+        .iter()
+        // embedded profiles don't necessarily have a directory
+        .flat_map(|profile| fs::read_dir(profile.dir()).into_iter().flatten())
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .flat_map(|year_dir| fs::read_dir(year_dir).unwrap())
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "enc"))
+        .map(|path| {
+            let encrypted = fs::read(&path).unwrap();
+            let plaintext = crypt::decrypt(&old, &encrypted)
+                .or_else(|err| match &staged {
+                    Some(staged) => crypt::decrypt(staged, &encrypted),
+                    None => Err(err),
+                })
+                .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+            (path, plaintext)
+        })
+        .collect::<Vec<_>>();
+
+    // then encrypt everything into temporary files, which only replace the inputs once
+    // they're all written - and the new key is stored before that, so whatever happens
+    // each input can be decrypted with one of the key files
+    let temp = |path: &Path| path.with_extension("enc.new");
+    for (path, plaintext) in &inputs {
+        fs::write(temp(path), crypt::encrypt(&new, plaintext)).unwrap();
+    }
+    fs::write(&new_key_file, new.to_hex()).unwrap();
+    for (path, _) in &inputs {
+        fs::rename(temp(path), path).unwrap();
+    }
+    if key_file.exists() {
+        fs::copy(&key_file, key_file.with_extension("old")).unwrap();
+    }
+    fs::rename(&new_key_file, &key_file).unwrap();
+    println!(
+        "re-encrypted {} inputs, new key written to {}",
+        inputs.len(),
+        key_file.display()
+    );
+}
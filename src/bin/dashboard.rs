@@ -0,0 +1,145 @@
+//! Full-screen overview of the calendar, the status of every part and their timings.
+//!
+//! Usage: `dashboard [--profile NAME] [--year YEAR]`. Move around with the arrow keys (or `hjkl`), then
+//! `r` runs the selected day against the known answers, `b` benchmarks it, `v` also
+//! checks it against the oracles on generated inputs and `a` runs every day. `q` quits.
+//!
+//! Only needs a terminal that understands ANSI escapes and `stty` to switch it to raw
+//! mode.
+
+use std::{
+    io::{self, BufRead, Write},
+    panic,
+    process::{Command, Stdio},
+};
+
+use aoc::{check::Action, dashboard::Dashboard, days, profile::Profile};
+
+/// Puts the terminal into raw mode on the alternate screen and restores it when dropped,
+/// including when we panic
+struct Terminal {
+    saved: String,
+}
+
+fn stty(args: &[&str]) -> String {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .expect("failed to run stty");
+    assert!(output.status.success(), "stty failed, is stdin a terminal?");
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+impl Terminal {
+    fn enter() -> Self {
+        let saved = stty(&["-g"]);
+        stty(&["raw", "-echo"]);
+        // switch to the alternate screen and hide the cursor
+        print!("\x1b[?1049h\x1b[?25l");
+        Self { saved }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        stty(&[&self.saved]);
+    }
+}
+
+enum Key {
+    Char(u8),
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Read the next key. Terminals send the escape sequence of an arrow key (`ESC [ A`)
+/// in one go, so an escape that arrives without a `[` right behind it was the escape
+/// key itself - waiting for more would hang until the next key press.
+fn read_key(stdin: &mut impl BufRead) -> Option<Key> {
+    let buf = stdin.fill_buf().ok()?;
+    let (key, len) = match *buf {
+        [] => return None,
+        [0x1b, b'[', b'A', ..] => (Key::Up, 3),
+        [0x1b, b'[', b'B', ..] => (Key::Down, 3),
+        [0x1b, b'[', b'C', ..] => (Key::Right, 3),
+        [0x1b, b'[', b'D', ..] => (Key::Left, 3),
+        // other sequences (like `ESC [ 3 ~` for delete) are skipped up to their final byte
+        [0x1b, b'[', ref rest @ ..] => {
+            let end = rest.iter().position(|b| (0x40..=0x7e).contains(b));
+            (Key::Char(0x1b), end.map_or(buf.len(), |end| end + 3))
+        }
+        [byte, ..] => (Key::Char(byte), 1),
+    };
+    stdin.consume(len);
+    Some(key)
+}
+
+fn draw(dashboard: &Dashboard) {
+    print!("{}", dashboard.render());
+    io::stdout().flush().unwrap();
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let year = days::take_year(&mut args);
+    let profile = match args.as_slice() {
+        [] => Profile::current(),
+        [flag, name] if flag == "--profile" => Profile::new(name),
+        _ => panic!("usage: dashboard [--profile NAME] [--year YEAR]"),
+    };
+    let mut dashboard = Dashboard::new(profile, year);
+
+    let terminal = Terminal::enter();
+    // panicking solvers would scribble all over the screen
+    panic::set_hook(Box::new(|_| {}));
+    let mut stdin = io::stdin().lock();
+    loop {
+        draw(&dashboard);
+        let Some(key) = read_key(&mut stdin) else {
+            break;
+        };
+        let action = match key {
+            Key::Left | Key::Char(b'h') => {
+                dashboard.select(-1);
+                None
+            }
+            Key::Right | Key::Char(b'l') => {
+                dashboard.select(1);
+                None
+            }
+            Key::Up | Key::Char(b'k') => {
+                dashboard.select(-5);
+                None
+            }
+            Key::Down | Key::Char(b'j') => {
+                dashboard.select(5);
+                None
+            }
+            Key::Char(b'r' | b'\r') => Some(Action::Run),
+            Key::Char(b'b') => Some(Action::Bench),
+            Key::Char(b'v') => Some(Action::Verify),
+            Key::Char(b'a') => {
+                dashboard.set_message("running all days...");
+                draw(&dashboard);
+                dashboard.run_all();
+                None
+            }
+            // ctrl-c doesn't raise a signal in raw mode
+            Key::Char(b'q' | 0x03) => break,
+            Key::Char(_) => None,
+        };
+        if let Some(action) = action {
+            let day = dashboard.selected;
+            dashboard.set_message(format!("working on day {day}..."));
+            draw(&dashboard);
+            dashboard.perform(action, day);
+        }
+    }
+    let _ = panic::take_hook();
+    drop(terminal);
+}
@@ -0,0 +1,58 @@
+//! Runs the fuzz targets and stores crashing inputs under `tests/regressions`.
+//!
+//! Like libFuzzer we stop fuzzing a target at its first crash: once the crash is fixed the
+//! next run will find the next one.
+//!
+//! Usage: `fuzz [TARGET...] [--iterations N] [--seed S]` - without any targets all of
+//! them get fuzzed.
+
+use std::panic;
+
+use aoc::{
+    fuzz::{self, Target},
+    rng::Rng,
+};
+
+fn main() {
+    let mut names = vec![];
+    let mut iterations = 10_000;
+    let mut seed = 0;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => iterations = args.next().unwrap().parse().unwrap(),
+            "--seed" => seed = args.next().unwrap().parse().unwrap(),
+            name => names.push(name.to_string()),
+        }
+    }
+    let targets: Vec<&Target> = if names.is_empty() {
+        fuzz::TARGETS.iter().collect()
+    } else {
+        names
+            .iter()
+            .map(|name| fuzz::target(name).unwrap_or_else(|| panic!("unknown target {name}")))
+            .collect()
+    };
+
+    // the crashes are reported below - the default hook would spam the terminal
+    panic::set_hook(Box::new(|_| {}));
+    let mut rng = Rng::seed_from(seed);
+    for target in targets {
+        let crash = (0..iterations).find_map(|i| {
+            let mut input = fuzz::seed_input(target.year, target.day, &mut rng);
+            fuzz::mutate(&mut rng, &mut input);
+            fuzz::crashes(target, &input).map(|msg| (i, msg, input))
+        });
+        match crash {
+            Some((i, msg, input)) => {
+                let path = fuzz::save_crash(target, &input).unwrap();
+                println!(
+                    "{}: iteration {i} crashed ({msg}), saved to {}",
+                    target.name,
+                    path.display()
+                );
+            }
+            None => println!("{}: {iterations} iterations, no crashes", target.name),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Rankings and solve times of a private leaderboard from its saved JSON export.
+//!
+//! Usage: `leaderboard FILE [rankings|times] [--scoring local|stars|time|delta] [--csv]` -
+//! shows the rankings under the site's own scoring by default.
+
+use std::fs;
+
+use aoc::leaderboard::{Leaderboard, Scoring};
+
+const USAGE: &str =
+    "usage: leaderboard FILE [rankings|times] [--scoring local|stars|time|delta] [--csv]";
+
+fn main() {
+    let mut scoring = Scoring::Local;
+    let mut csv = false;
+    let mut args = vec![];
+    let mut it = std::env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--scoring" => {
+                let name = it.next().expect(USAGE);
+                scoring = name
+                    .parse()
+                    .unwrap_or_else(|()| panic!("unknown scoring {name}"));
+            }
+            "--csv" => csv = true,
+            _ => args.push(arg),
+        }
+    }
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    let (path, view) = match args.as_slice() {
+        [path] => (path, "rankings"),
+        [path, view] => (path, *view),
+        _ => panic!("{USAGE}"),
+    };
+
+    let leaderboard: Leaderboard = fs::read_to_string(path)
+        .unwrap()
+        .parse()
+        .unwrap_or_else(|err| panic!("{path} isn't a leaderboard export: {err}"));
+    let table = match view {
+        "rankings" => leaderboard.rankings_table(scoring),
+        "times" => leaderboard.times_table(),
+        _ => panic!("{USAGE}"),
+    };
+    if csv {
+        print!("{}", table.to_csv());
+    } else {
+        print!("{table}");
+    }
+}
@@ -0,0 +1,53 @@
+//! Shrinks a failing input down to a minimal one.
+//!
+//! Usage: `minimize DAY (disagree|panics) INPUT [-o OUTPUT] [--year YEAR]` - the output
+//! defaults to `INPUT.min`.
+
+use std::{fs, panic};
+
+use aoc::{
+    days,
+    minimize::{self, Predicate},
+};
+
+const USAGE: &str = "usage: minimize DAY (disagree|panics) INPUT [-o OUTPUT] [--year YEAR]";
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let year = days::take_year(&mut args);
+    let [day, predicate, path, rest @ ..] = args.as_slice() else {
+        panic!("{USAGE}");
+    };
+    let day: usize = day.parse().unwrap();
+    let predicate: Predicate = predicate
+        .parse()
+        .unwrap_or_else(|()| panic!("unknown predicate {predicate}"));
+    let output = match rest {
+        [flag, output] if flag == "-o" => output.clone(),
+        [] => format!("{path}.min"),
+        _ => panic!("{USAGE}"),
+    };
+
+    let input = fs::read_to_string(path).unwrap();
+    assert!(
+        predicate.holds(year, day, &input),
+        "{path} doesn't fail in the first place"
+    );
+    // we're going to see a lot of panics while shrinking
+    panic::set_hook(Box::new(|_| {}));
+    let mut tests = 0;
+    let minimal = minimize::minimize(&input, |candidate| {
+        tests += 1;
+        predicate.holds(year, day, candidate)
+    });
+    let _ = panic::take_hook();
+
+    fs::write(&output, &minimal).unwrap();
+    println!(
+        "shrunk {} lines ({} bytes) to {} lines ({} bytes) in {tests} tests, written to {output}",
+        input.lines().count(),
+        input.len(),
+        minimal.lines().count(),
+        minimal.len(),
+    );
+}
@@ -0,0 +1,53 @@
+//! Caches a saved puzzle page as Markdown and offers the examples in it as test cases.
+//!
+//! Usage: `puzzle DAY PAGE [--save] [--year YEAR]` - prints the example candidates found in `PAGE`;
+//! with `--save` they're added to the day's examples, which the tests check the solutions
+//! against.
+
+use std::fs;
+
+use aoc::{
+    days,
+    puzzle::{Examples, Page},
+};
+
+const USAGE: &str = "usage: puzzle DAY PAGE [--save] [--year YEAR]";
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let year = days::take_year(&mut args);
+    let (day, path, save) = match args.as_slice() {
+        [day, path] => (day, path, false),
+        [day, path, flag] if flag == "--save" => (day, path, true),
+        _ => panic!("{USAGE}"),
+    };
+    let day: usize = day.parse().expect(USAGE);
+
+    let page = Page::parse(&fs::read_to_string(path).unwrap());
+    if page.articles.is_empty() {
+        panic!("{path} doesn't contain a puzzle description");
+    }
+    println!(
+        "description saved to {}",
+        page.cache(year, day).unwrap().display()
+    );
+
+    let candidates = page.examples();
+    if candidates.is_empty() {
+        println!("couldn't find any examples");
+    }
+    let mut examples = Examples::load(year, day);
+    for example in candidates {
+        print!("\n{example}");
+        if save {
+            if examples.add(example) {
+                println!("(added)");
+            } else {
+                println!("(already known)");
+            }
+        }
+    }
+    if save {
+        examples.save(year, day).unwrap();
+    }
+}
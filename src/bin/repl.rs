@@ -0,0 +1,47 @@
+//! Interactive exploration of the parsed inputs.
+//!
+//! Usage: `repl [--year YEAR] [DAY [PATH]]` loads the given day right away, then reads
+//! commands from stdin; `help` lists them. The year defaults to the one in the
+//! [config](aoc::config), or else the latest one.
+
+use std::io::{self, BufRead, Write};
+
+use aoc::{days, repl::Session};
+
+const USAGE: &str = "usage: repl [--year YEAR] [DAY [PATH]]";
+
+fn main() {
+    let mut year = days::default_year();
+    let mut args = vec![];
+    let mut it = std::env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--year" => year = it.next().expect(USAGE).parse().expect(USAGE),
+            _ => args.push(arg),
+        }
+    }
+    let mut session = Session::new(year);
+    if !args.is_empty() {
+        report(session.execute(&format!("load {}", args.join(" "))));
+    }
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+        let Some(line) = lines.next() else { break };
+        let line = line.unwrap();
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+        report(session.execute(&line));
+    }
+}
+
+fn report(reply: Result<String, String>) {
+    match reply {
+        Ok(out) if out.is_empty() => {}
+        Ok(out) => println!("{out}"),
+        Err(err) => println!("error: {err}"),
+    }
+}
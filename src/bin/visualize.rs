@@ -0,0 +1,80 @@
+//! Renders the visualization of a day to image files, or plays it in the terminal.
+//!
+//! Usage: `visualize DAY [--year YEAR] [--input PATH] [--format ppm|png|gif|svg]
+//! [--scale N] [--delay CENTISECONDS] [--out PATH] [--play]`
+//!
+//! The input is taken from `PATH` (`-` for stdin) if given and otherwise the stored one
+//! of the current profile is used. A GIF (the default) is written to `--out`, which
+//! defaults to `YEAR_day_DD.gif`; PPM and PNG frames go into the directory `--out`,
+//! `YEAR_day_DD` by default. Days with a drawing of their puzzle can also be written as
+//! an SVG, to `YEAR_day_DD.svg` by default. With `--play` the frames are animated in
+//! the terminal instead (see `aoc::visualize::terminal` for the keys), at a scale of 1
+//! unless given.
+
+use std::{fs, path::PathBuf, time::Duration};
+
+use aoc::{
+    days,
+    input::Source,
+    profile::Profile,
+    visualize::{self, terminal, Format},
+};
+
+const USAGE: &str = "usage: visualize DAY [--year YEAR] [--input PATH] [--format ppm|png|gif|svg] \
+    [--scale N] [--delay CENTISECONDS] [--out PATH] [--play]";
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let year = days::take_year(&mut args);
+    let mut source = Source::Stored(Profile::current());
+    let mut format = Format::Gif;
+    let mut drawing = false;
+    let (mut scale, mut delay) = (None, 10);
+    let mut out = None;
+    let mut play = false;
+    let mut day = None;
+    let mut it = args.into_iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--input" => source = Source::from_arg(&it.next().expect(USAGE)),
+            "--format" => match it.next().expect(USAGE).as_str() {
+                "svg" => drawing = true,
+                other => format = other.parse().expect(USAGE),
+            },
+            "--scale" => scale = Some(it.next().expect(USAGE).parse().expect(USAGE)),
+            "--delay" => delay = it.next().expect(USAGE).parse().expect(USAGE),
+            "--out" => out = Some(PathBuf::from(it.next().expect(USAGE))),
+            "--play" => play = true,
+            _ if day.is_none() => day = Some(arg.parse::<usize>().expect(USAGE)),
+            _ => panic!("{USAGE}"),
+        }
+    }
+    let day = day.expect(USAGE);
+
+    let input = source
+        .read(year, day)
+        .unwrap_or_else(|err| panic!("can't load the input of {year} day {day}: {err}"));
+    if drawing {
+        let svg = visualize::drawing(year, day, &input)
+            .unwrap_or_else(|| panic!("{year} day {day} has no drawing"));
+        let out = out.unwrap_or_else(|| PathBuf::from(format!("{year}_day_{day:02}.svg")));
+        fs::write(&out, svg.to_string()).unwrap();
+        println!("wrote {}", out.display());
+        return;
+    }
+    let frames = visualize::frames(year, day, &input)
+        .unwrap_or_else(|| panic!("{year} day {day} has no visualization"))
+        .iter()
+        .map(|frame| frame.scaled(scale.unwrap_or(if play { 1 } else { 4 })))
+        .collect::<Vec<_>>();
+    if play {
+        terminal::play(frames, Duration::from_millis(10 * delay as u64)).unwrap();
+        return;
+    }
+    let out = out.unwrap_or_else(|| match format {
+        Format::Gif => PathBuf::from(format!("{year}_day_{day:02}.gif")),
+        _ => PathBuf::from(format!("{year}_day_{day:02}")),
+    });
+    visualize::export(&frames, format, &out, delay).unwrap();
+    println!("wrote {} frames to {}", frames.len(), out.display());
+}
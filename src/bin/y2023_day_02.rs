@@ -0,0 +1,8 @@
+use aoc::y2023::day_02::{fst, snd};
+
+fn main() {
+    let input = aoc::input::from_cli(2023, 2);
+    aoc::validate::y2023::day_02::SCHEMA.enforce(&input);
+    dbg!(fst(&input));
+    dbg!(snd(&input));
+}
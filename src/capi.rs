@@ -0,0 +1,125 @@
+//! The C API of the shared library, behind the `capi` feature.
+//!
+//! The declarations in `include/aoc.h` are generated from this module: the build script
+//! writes them to its output directory whenever the feature is enabled, and the tests
+//! fail while the committed header differs. Run them with `UPDATE_SNAPSHOTS=1` to update
+//! it:
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test --features capi --test capi
+//! ```
+//!
+//! All functions are safe to call from any thread and never unwind into the caller: a
+//! panicking solver is reported as [`AocStatus::Panicked`].
+//!
+//! ```c
+//! char answer[AOC_ANSWER_LEN];
+//! AocStatus status = aoc_solve(2023, 4, 1, input, input_len, answer, sizeof answer);
+//! if (status != AOC_STATUS_OK) {
+//!     fprintf(stderr, "%s\n", aoc_status_message(status));
+//! }
+//! ```
+
+use std::{ffi::c_char, slice};
+
+use crate::{
+    days,
+    differential::{self, Outcome},
+    validate::{self, Severity},
+};
+
+/// Size of an output buffer that's big enough for any answer and the terminating NUL
+pub const AOC_ANSWER_LEN: usize = 32;
+
+/// What became of a call
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    /// The answer was written to the output buffer
+    Ok = 0,
+    /// There's no solver for the year, day and part
+    Unimplemented = 1,
+    /// The input or the output buffer is a null pointer (and not empty)
+    NullPointer = 2,
+    /// The input isn't valid UTF-8
+    InvalidUtf8 = 3,
+    /// The input breaks the assumptions of the day's solutions
+    InvalidInput = 4,
+    /// The solver panicked
+    Panicked = 5,
+    /// The answer (with its NUL) doesn't fit in the output buffer
+    BufferTooSmall = 6,
+}
+
+/// Solve a part (1 or 2) of a day on an input of `len` bytes, writing the answer as a
+/// NUL-terminated decimal string to `out_buf`, which has room for `out_len` bytes.
+///
+/// # Safety
+///
+/// `input_ptr` has to point to `len` readable bytes and `out_buf` to `out_len` writable
+/// ones, unless the respective length is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u32,
+    day: u32,
+    part: u32,
+    input_ptr: *const u8,
+    len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> AocStatus {
+    let (year, day) = (year as usize, day as usize);
+    let Some(solver) = days::get(year, day)
+        .and_then(|solutions| *solutions.solvers.get((part as usize).wrapping_sub(1))?)
+    else {
+        return AocStatus::Unimplemented;
+    };
+    if (input_ptr.is_null() && len != 0) || (out_buf.is_null() && out_len != 0) {
+        return AocStatus::NullPointer;
+    }
+    let bytes = if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(input_ptr, len)
+    };
+    let Ok(input) = std::str::from_utf8(bytes) else {
+        return AocStatus::InvalidUtf8;
+    };
+    if validate::schema(year, day).is_some_and(|schema| {
+        schema
+            .validate(input)
+            .iter()
+            .any(|violation| violation.severity == Severity::Error)
+    }) {
+        return AocStatus::InvalidInput;
+    }
+    let Outcome::Answer(answer) = differential::run(solver, input) else {
+        return AocStatus::Panicked;
+    };
+
+    let answer = answer.to_string();
+    if answer.len() >= out_len {
+        return AocStatus::BufferTooSmall;
+    }
+    let out = slice::from_raw_parts_mut(out_buf.cast::<u8>(), out_len);
+    out[..answer.len()].copy_from_slice(answer.as_bytes());
+    out[answer.len()] = 0;
+    AocStatus::Ok
+}
+
+/// A static, NUL-terminated description of a status (taken as a plain integer so that
+/// unknown values are fine)
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: i32) -> *const c_char {
+    let message: &'static [u8] = match status {
+        0 => b"ok\0",
+        1 => b"not implemented\0",
+        2 => b"null pointer\0",
+        3 => b"input isn't valid UTF-8\0",
+        4 => b"input violates the solution's assumptions\0",
+        5 => b"the solver panicked\0",
+        6 => b"output buffer too small\0",
+        _ => b"unknown status\0",
+    };
+    message.as_ptr().cast()
+}
@@ -0,0 +1,316 @@
+//! Running, benchmarking and verifying the solutions of a day, as the runner and the
+//! dashboard both do.
+//!
+//! How long a part may run, how long it's benchmarked for and how many generated inputs
+//! it's verified on all come from the [config](crate::config). The variants of
+//! [plugins](crate::plugin) are checked the same way as our solvers.
+
+use std::{
+    fmt,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::Answers,
+    config::Config,
+    days,
+    differential::{self, Mismatch, Outcome},
+    generate,
+    plugin::Variant,
+    rng::Rng,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Solve once and check the answer against the known one
+    Run,
+    /// Like running, but rerun for a while to get a steady timing
+    Bench,
+    /// Like running, but also compare against the oracle on generated inputs
+    Verify,
+}
+
+/// How a part fared
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Produced an answer we can't check yet
+    Unverified(usize),
+    /// Produced the known answer (and agreed with the oracle if it was verified)
+    Correct(usize),
+    Wrong {
+        answer: usize,
+        expected: usize,
+    },
+    Panicked(String),
+    TimedOut(Duration),
+    /// Disagrees with its oracle on the input generated from some seed
+    Disagrees {
+        seed: u64,
+        mismatch: Mismatch,
+    },
+    Unimplemented,
+}
+
+impl Status {
+    /// A short name for the kind of status, for machine-readable output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Status::Unverified(_) => "unverified",
+            Status::Correct(_) => "correct",
+            Status::Wrong { .. } => "wrong",
+            Status::Panicked(_) => "panicked",
+            Status::TimedOut(_) => "timeout",
+            Status::Disagrees { .. } => "disagrees",
+            Status::Unimplemented => "unimplemented",
+        }
+    }
+
+    pub fn answer(&self) -> Option<usize> {
+        match self {
+            Status::Unverified(answer) | Status::Correct(answer) => Some(*answer),
+            Status::Wrong { answer, .. } => Some(*answer),
+            _ => None,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        !matches!(
+            self,
+            Status::Unverified(_) | Status::Correct(_) | Status::Unimplemented
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Unverified(answer) => write!(f, "{answer} (unverified)"),
+            Status::Correct(answer) => write!(f, "{answer} (correct)"),
+            Status::Wrong { answer, expected } => {
+                write!(f, "{answer} (wrong, expected {expected})")
+            }
+            Status::Panicked(msg) => write!(f, "panicked ({msg})"),
+            Status::TimedOut(after) => write!(f, "timed out after {}", format_duration(*after)),
+            Status::Disagrees { seed, mismatch } => write!(
+                f,
+                "disagrees with the oracle on seed {seed} ({} vs {})",
+                mismatch.solver, mismatch.oracle
+            ),
+            Status::Unimplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+/// The result of checking one part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub part: usize,
+    pub status: Status,
+    /// How long solving took (the median when benchmarking)
+    pub time: Option<Duration>,
+}
+
+/// Format a duration with a unit below a minute (`12ns`, `3.4ms`, `5.67s`) and as
+/// `h:mm:ss` from there on, which covers both run times and solve times
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    let s = duration.as_secs();
+    match nanos {
+        0 => "0s".to_string(),
+        1..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ if s < 60 => format!("{:.2}s", nanos as f64 / 1e9),
+        _ => format!("{}:{:02}:{:02}", s / 3600, s / 60 % 60, s % 60),
+    }
+}
+
+/// Check both parts of a day on some input against the known answers
+pub fn day(
+    year: usize,
+    day: usize,
+    input: &str,
+    answers: &Answers,
+    action: Action,
+    config: &Config,
+) -> Vec<Report> {
+    let solutions =
+        days::get(year, day).unwrap_or_else(|| panic!("{year} day {day} isn't implemented"));
+    (1..)
+        .zip(solutions.solvers)
+        .map(|(part, solver)| match solver {
+            Some(solver) => self::part(
+                year,
+                day,
+                part,
+                move |input: &str| differential::run(solver, input),
+                input,
+                answers.get(day, part),
+                action,
+                config,
+            ),
+            None => Report {
+                part,
+                status: Status::Unimplemented,
+                time: None,
+            },
+        })
+        .collect()
+}
+
+/// Check a plugin's variant of a part on some input like one of our solvers. Without a
+/// known answer it has to agree with what our solver said (`reference`).
+pub fn variant(
+    variant: &Variant,
+    input: &str,
+    answers: &Answers,
+    reference: Option<usize>,
+    action: Action,
+    config: &Config,
+) -> Report {
+    let solve = variant.clone();
+    let mut report = part(
+        variant.year,
+        variant.day,
+        variant.part,
+        move |input: &str| solve.run(input),
+        input,
+        answers.get(variant.day, variant.part),
+        action,
+        config,
+    );
+    if let (Status::Unverified(answer), Some(expected)) = (&report.status, reference) {
+        if *answer != expected {
+            report.status = Status::Wrong {
+                answer: *answer,
+                expected,
+            };
+        }
+    }
+    report
+}
+
+/// Check one part on some input, solved by some function
+#[allow(clippy::too_many_arguments)]
+fn part(
+    year: usize,
+    day: usize,
+    part: usize,
+    solve: impl Fn(&str) -> Outcome + Clone + Send + 'static,
+    input: &str,
+    known: Option<usize>,
+    action: Action,
+    config: &Config,
+) -> Report {
+    // every run is limited, not just the first one
+    let timeout = config.timeout();
+    let run = |input: &str| time_limited(solve.clone(), input, timeout);
+    let timed_out = Report {
+        part,
+        status: Status::TimedOut(timeout),
+        time: None,
+    };
+    let Some((outcome, mut time)) = run(input) else {
+        return timed_out;
+    };
+    let mut status = match (outcome, known) {
+        (Outcome::Panic(msg), _) => Status::Panicked(msg),
+        (Outcome::Answer(answer), None) => Status::Unverified(answer),
+        (Outcome::Answer(answer), Some(known)) if answer == known => Status::Correct(answer),
+        (Outcome::Answer(answer), Some(expected)) => Status::Wrong { answer, expected },
+    };
+    if !status.is_failure() {
+        match action {
+            Action::Run => {}
+            Action::Bench => match bench(&run, input, time, config) {
+                Some(median) => time = median,
+                None => return timed_out,
+            },
+            Action::Verify => match disagreement(year, day, part, &run, config.verify_seeds()) {
+                Ok(Some((seed, mismatch))) => status = Status::Disagrees { seed, mismatch },
+                Ok(None) => {}
+                Err(()) => return timed_out,
+            },
+        }
+    }
+    Report {
+        part,
+        status,
+        time: Some(time),
+    }
+}
+
+/// Run a solver once, timing it
+fn time(solve: &impl Fn(&str) -> Outcome, input: &str) -> (Outcome, Duration) {
+    let start = Instant::now();
+    let outcome = solve(input);
+    (outcome, start.elapsed())
+}
+
+/// Run a solver once on another thread, giving up on it after some time (it's left
+/// running in the background since threads can't be killed)
+fn time_limited(
+    solve: impl Fn(&str) -> Outcome + Send + 'static,
+    input: &str,
+    timeout: Duration,
+) -> Option<(Outcome, Duration)> {
+    let (tx, rx) = mpsc::channel();
+    let input = input.to_string();
+    thread::spawn(move || tx.send(time(&solve, &input)));
+    rx.recv_timeout(timeout).ok()
+}
+
+/// Rerun a solver for a while after a first run, returning its median time (`None` if a
+/// run timed out)
+fn bench(
+    run: &impl Fn(&str) -> Option<(Outcome, Duration)>,
+    input: &str,
+    first: Duration,
+    config: &Config,
+) -> Option<Duration> {
+    let start = Instant::now();
+    let mut times = vec![first];
+    while start.elapsed() < config.bench_time() && times.len() < config.bench_iterations() {
+        times.push(run(input)?.1);
+    }
+    times.sort();
+    Some(times[times.len() / 2])
+}
+
+/// Look for a generated input on which a solver of a part disagrees with its oracle
+/// (`Err` if the solver timed out on one)
+fn disagreement(
+    year: usize,
+    day: usize,
+    part: usize,
+    run: &impl Fn(&str) -> Option<(Outcome, Duration)>,
+    seeds: u64,
+) -> Result<Option<(u64, Mismatch)>, ()> {
+    let Some(oracle) =
+        days::get(year, day).and_then(|solutions| *solutions.oracles.get(part.wrapping_sub(1))?)
+    else {
+        return Ok(None);
+    };
+    for seed in 0..seeds {
+        let Some(input) = generate::generate(year, day, &mut Rng::seed_from(seed)) else {
+            continue;
+        };
+        let (solver, _) = run(&input).ok_or(())?;
+        let oracle = differential::run(oracle, &input);
+        if solver != oracle {
+            return Ok(Some((
+                seed,
+                Mismatch {
+                    year,
+                    day,
+                    part,
+                    solver,
+                    oracle,
+                },
+            )));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,224 @@
+//! Project configuration.
+//!
+//! Settings are read from `aoc.toml` in the project root, and `aoc.local.toml` next to it
+//! (which isn't committed) can override any of them for one person. Both are optional and
+//! so is every key in them; command line flags in turn override whatever the files say.
+//!
+//! ```toml
+//! year = 2023          # the year the binaries default to (the latest one if unset)
+//! format = "text"      # how results are printed: "text" or "csv"
+//! timeout = 60         # seconds a solution may run before it's given up on
+//!
+//! [paths]              # relative to the project root
+//! inputs = "inputs"
+//! puzzles = "puzzles"
+//! session = ".aoc-key" # the secret the inputs are encrypted with, see `aoc::crypt`
+//! plugins = "plugins"  # shared libraries with more solutions, see `aoc::plugin`
+//!
+//! [bench]
+//! iterations = 1000    # the most runs to time per part
+//! time = 1.0           # seconds to keep rerunning a part for
+//!
+//! [verify]
+//! seeds = 20           # generated inputs to compare against the oracles
+//! ```
+//!
+//! Unknown keys are an error rather than silently ignored, so that a typo doesn't leave
+//! a setting at its default without anyone noticing. So are durations that can't be one
+//! (negative, `nan` or too large).
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use once_cell::sync::Lazy;
+use serde::{de, Deserialize, Deserializer};
+
+pub const FILE: &str = "aoc.toml";
+pub const LOCAL_FILE: &str = "aoc.local.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "csv" => Ok(Self::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub year: Option<usize>,
+    pub format: Option<Format>,
+    /// In seconds
+    #[serde(default, deserialize_with = "seconds")]
+    pub timeout: Option<f64>,
+    #[serde(default)]
+    pub paths: Paths,
+    #[serde(default)]
+    pub bench: Bench,
+    #[serde(default)]
+    pub verify: Verify,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Paths {
+    pub inputs: Option<PathBuf>,
+    pub puzzles: Option<PathBuf>,
+    pub session: Option<PathBuf>,
+    pub plugins: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Bench {
+    pub iterations: Option<usize>,
+    /// In seconds
+    #[serde(default, deserialize_with = "seconds")]
+    pub time: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Verify {
+    pub seeds: Option<u64>,
+}
+
+/// Whether a number of seconds can be a [`Duration`]
+pub fn valid_seconds(seconds: f64) -> bool {
+    Duration::try_from_secs_f64(seconds).is_ok()
+}
+
+fn seconds<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    let seconds = f64::deserialize(deserializer)?;
+    if !valid_seconds(seconds) {
+        return Err(de::Error::custom(format!(
+            "invalid duration {seconds}, expected a non-negative number of seconds"
+        )));
+    }
+    Ok(Some(seconds))
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, err) => write!(f, "can't read {}: {err}", path.display()),
+            // toml's messages already point at the offending line
+            Error::Parse(path, err) => write!(f, "invalid config in {}:\n{err}", path.display()),
+        }
+    }
+}
+
+/// The directory the config files (and by default everything else) live in
+pub fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+static CONFIG: Lazy<Config> = Lazy::new(|| Config::load().unwrap_or_else(|err| panic!("{err}")));
+
+impl Config {
+    pub fn parse(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+
+    /// Read a config file, if it exists
+    pub fn read(path: &Path) -> Result<Option<Self>, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map(Some)
+                .map_err(|err| Error::Parse(path.to_path_buf(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(Error::Io(path.to_path_buf(), err)),
+        }
+    }
+
+    /// Read the project's config with the local overrides applied
+    pub fn load() -> Result<Self, Error> {
+        let project = Self::read(&root().join(FILE))?.unwrap_or_default();
+        let local = Self::read(&root().join(LOCAL_FILE))?.unwrap_or_default();
+        Ok(project.merge(local))
+    }
+
+    /// The config everything uses, loaded on first use
+    pub fn get() -> &'static Self {
+        &CONFIG
+    }
+
+    /// Apply the settings of another config on top of this one
+    pub fn merge(self, over: Self) -> Self {
+        Self {
+            year: over.year.or(self.year),
+            format: over.format.or(self.format),
+            timeout: over.timeout.or(self.timeout),
+            paths: Paths {
+                inputs: over.paths.inputs.or(self.paths.inputs),
+                puzzles: over.paths.puzzles.or(self.paths.puzzles),
+                session: over.paths.session.or(self.paths.session),
+                plugins: over.paths.plugins.or(self.paths.plugins),
+            },
+            bench: Bench {
+                iterations: over.bench.iterations.or(self.bench.iterations),
+                time: over.bench.time.or(self.bench.time),
+            },
+            verify: Verify {
+                seeds: over.verify.seeds.or(self.verify.seeds),
+            },
+        }
+    }
+
+    pub fn inputs_dir(&self) -> PathBuf {
+        root().join(self.paths.inputs.as_deref().unwrap_or("inputs".as_ref()))
+    }
+
+    pub fn puzzles_dir(&self) -> PathBuf {
+        root().join(self.paths.puzzles.as_deref().unwrap_or("puzzles".as_ref()))
+    }
+
+    pub fn session_file(&self) -> PathBuf {
+        root().join(self.paths.session.as_deref().unwrap_or(".aoc-key".as_ref()))
+    }
+
+    pub fn plugins_dir(&self) -> PathBuf {
+        root().join(self.paths.plugins.as_deref().unwrap_or("plugins".as_ref()))
+    }
+
+    pub fn format(&self) -> Format {
+        self.format.unwrap_or(Format::Text)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs_f64(self.timeout.unwrap_or(60.0))
+    }
+
+    pub fn bench_iterations(&self) -> usize {
+        self.bench.iterations.unwrap_or(1000)
+    }
+
+    pub fn bench_time(&self) -> Duration {
+        Duration::from_secs_f64(self.bench.time.unwrap_or(1.0))
+    }
+
+    pub fn verify_seeds(&self) -> u64 {
+        self.verify.seeds.unwrap_or(20)
+    }
+}
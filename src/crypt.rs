@@ -0,0 +1,126 @@
+//! Encryption of the puzzle inputs.
+//!
+//! The puzzle author asks people not to publish their inputs, so the repository only
+//! contains them encrypted (ChaCha20-Poly1305). The key is read from the `AOC_KEY`
+//! environment variable or - if that isn't set - from the key file (`.aoc-key` in the
+//! crate root unless `paths.session` in the config says otherwise), both hex encoded. An encrypted file is the magic bytes, a random nonce and the
+//! ciphertext.
+
+use std::{fmt, fs, io, path::PathBuf};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Nonce,
+};
+
+use crate::config::Config;
+
+pub const KEY_ENV: &str = "AOC_KEY";
+const MAGIC: &[u8; 4] = b"AOC1";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Neither the environment variable nor the key file exist
+    NoKey,
+    InvalidKey,
+    /// The file isn't something we've encrypted
+    Corrupted,
+    /// Decryption failed - most likely the key is wrong
+    Decryption,
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoKey => write!(
+                f,
+                "no input key: set {KEY_ENV} or create {} (see `crypt keygen`)",
+                key_file().display()
+            ),
+            Error::InvalidKey => write!(f, "the input key has to be 64 hex digits"),
+            Error::Corrupted => write!(f, "not an encrypted input"),
+            Error::Decryption => write!(f, "decryption failed - is it the right key?"),
+            Error::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Location of the local key file
+pub fn key_file() -> PathBuf {
+    Config::get().session_file()
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    /// Load the key from the environment or the key file
+    pub fn load() -> Result<Self, Error> {
+        match std::env::var(KEY_ENV) {
+            Ok(hex) => Self::from_hex(&hex),
+            Err(_) => match fs::read_to_string(key_file()) {
+                Ok(hex) => Self::from_hex(&hex),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Err(Error::NoKey),
+                Err(err) => Err(err.into()),
+            },
+        }
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let hex = hex.trim();
+        // `from_str_radix` alone would also take signs like in `+f`
+        if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Error::InvalidKey);
+        }
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).unwrap();
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| Error::InvalidKey)?;
+        }
+        Ok(Self(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // keep the key out of any logs
+        write!(f, "Key(..)")
+    }
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key.cipher().encrypt(&nonce, plaintext).unwrap();
+    [MAGIC.as_slice(), nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, Error> {
+    let data = data.strip_prefix(MAGIC).ok_or(Error::Corrupted)?;
+    if data.len() < NONCE_LEN {
+        return Err(Error::Corrupted);
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    key.cipher()
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| Error::Decryption)
+}
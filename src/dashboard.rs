@@ -0,0 +1,220 @@
+//! The state behind the terminal dashboard and how it's drawn.
+//!
+//! Everything here renders to plain strings with ANSI escapes; dealing with the terminal
+//! itself (raw mode, reading keys) is left to the `dashboard` binary. The last timing of
+//! every part is kept in `timings.txt` in the profile's directory for the year, one
+//! `DAY PART NANOSECONDS` line per part, so they survive between sessions.
+
+use std::{collections::BTreeMap, fmt::Write as _, fs, io, path::PathBuf, time::Duration};
+
+use crate::{
+    answers::Answers,
+    check::{self, format_duration, Action, Status},
+    config::Config,
+    days,
+    input::Source,
+    profile::Profile,
+    validate::{self, Severity},
+};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const INVERSE: &str = "\x1b[7m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+
+/// How to show the status of a part, `None` if it hasn't been run this session
+fn describe(status: Option<&Status>) -> (&'static str, String) {
+    match status {
+        None => (DIM, "not run".to_string()),
+        Some(Status::Unverified(answer)) => (YELLOW, format!("solved: {answer} (unverified)")),
+        Some(Status::Correct(answer)) => (GREEN, format!("verified: {answer}")),
+        Some(Status::Unimplemented) => (DIM, "unimplemented".to_string()),
+        Some(failure) => (RED, format!("failing: {failure}")),
+    }
+}
+
+pub struct Dashboard {
+    pub profile: Profile,
+    pub year: usize,
+    /// The selected day, 1 to 25
+    pub selected: usize,
+    answers: Answers,
+    /// Only has the parts that have been run (or can't be)
+    statuses: BTreeMap<(usize, usize), Status>,
+    timings: BTreeMap<(usize, usize), Duration>,
+    message: String,
+}
+
+fn timings_path(profile: &Profile, year: usize) -> PathBuf {
+    profile.year_dir(year).join("timings.txt")
+}
+
+fn load_timings(profile: &Profile, year: usize) -> BTreeMap<(usize, usize), Duration> {
+    let Ok(content) = fs::read_to_string(timings_path(profile, year)) else {
+        return BTreeMap::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields = line
+                .split_ascii_whitespace()
+                .map(|s| s.parse::<u64>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            let [day, part, nanos] = fields[..] else {
+                return None;
+            };
+            Some(((day as usize, part as usize), Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+impl Dashboard {
+    pub fn new(profile: Profile, year: usize) -> Self {
+        let statuses = (1..=25)
+            .flat_map(|day| {
+                let solutions = days::get(year, day);
+                (1..=2)
+                    .filter(move |&part| solutions.and_then(|d| d.solvers[part - 1]).is_none())
+                    .map(move |part| ((day, part), Status::Unimplemented))
+            })
+            .collect();
+        Self {
+            answers: Answers::load(&profile, year),
+            timings: load_timings(&profile, year),
+            profile,
+            year,
+            selected: 1,
+            statuses,
+            message: "r: run  b: benchmark  v: verify  a: run all  arrows/hjkl: move  q: quit"
+                .to_string(),
+        }
+    }
+
+    /// Move the selection by some number of days, staying on the calendar
+    pub fn select(&mut self, by: isize) {
+        self.selected = self.selected.saturating_add_signed(by).clamp(1, 25);
+    }
+
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = message.into();
+    }
+
+    pub fn save_timings(&self) -> io::Result<()> {
+        fs::create_dir_all(self.profile.year_dir(self.year))?;
+        fs::write(
+            timings_path(&self.profile, self.year),
+            self.timings
+                .iter()
+                .map(|((day, part), time)| format!("{day} {part} {}\n", time.as_nanos()))
+                .collect::<String>(),
+        )
+    }
+
+    /// Run, benchmark or verify both parts of a day, updating their status and timing
+    pub fn perform(&mut self, action: Action, day: usize) {
+        if days::get(self.year, day).is_none() {
+            self.message = format!("day {day} isn't implemented");
+            return;
+        }
+        let input = match Source::Stored(self.profile.clone()).read(self.year, day) {
+            Ok(input) => input,
+            Err(err) => {
+                self.message = format!("day {day}: can't load input: {err}");
+                return;
+            }
+        };
+        if let Some(violation) = validate::schema(self.year, day)
+            .into_iter()
+            .flat_map(|schema| schema.validate(&input))
+            .find(|violation| violation.severity == Severity::Error)
+        {
+            self.message = format!("day {day}: invalid input: {violation}");
+            return;
+        }
+
+        let reports = check::day(self.year, day, &input, &self.answers, action, Config::get());
+        for report in reports {
+            if let Some(time) = report.time {
+                self.timings.insert((day, report.part), time);
+            }
+            self.statuses.insert((day, report.part), report.status);
+        }
+        let verb = match action {
+            Action::Run => "ran",
+            Action::Bench => "benchmarked",
+            Action::Verify => "verified",
+        };
+        self.message = format!("{verb} day {day}");
+        if let Err(err) = self.save_timings() {
+            self.message = format!("{}; couldn't save timings: {err}", self.message);
+        }
+    }
+
+    /// Run every implemented day
+    pub fn run_all(&mut self) {
+        for day in 1..=25 {
+            if days::get(self.year, day).is_some() {
+                self.perform(Action::Run, day);
+            }
+        }
+        self.message = "ran all days".to_string();
+    }
+
+    fn stars(&self, day: usize) -> usize {
+        (1..=2)
+            .filter(|&part| self.answers.get(day, part).is_some())
+            .count()
+    }
+
+    /// Draw the whole screen
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        // home the cursor and clear the screen
+        out.push_str("\x1b[H\x1b[2J");
+        let total = (1..=25).map(|day| self.stars(day)).sum::<usize>();
+        let _ = write!(
+            out,
+            "{BOLD}Advent of Code {}{RESET} [{}]  {YELLOW}{total}*{RESET}\r\n\r\n",
+            self.year, self.profile
+        );
+
+        for week in 0..5 {
+            out.push_str("  ");
+            for day in week * 5 + 1..=week * 5 + 5 {
+                let stars = self.stars(day);
+                let style = if days::get(self.year, day).is_some() {
+                    ""
+                } else {
+                    DIM
+                };
+                let selected = if day == self.selected { INVERSE } else { "" };
+                let _ = write!(
+                    out,
+                    "{selected}{style} {day:>2} {YELLOW}{}{DIM}{}{RESET}  ",
+                    "*".repeat(stars),
+                    "*".repeat(2 - stars),
+                );
+            }
+            out.push_str("\r\n");
+        }
+
+        let _ = write!(out, "\r\n{BOLD}Day {}{RESET}\r\n", self.selected);
+        for part in 1..=2 {
+            let (color, status) = describe(self.statuses.get(&(self.selected, part)));
+            let timing = self
+                .timings
+                .get(&(self.selected, part))
+                .map(|&time| format_duration(time))
+                .unwrap_or_else(|| "-".to_string());
+            let _ = write!(
+                out,
+                "  part {part}  {color}{status}{RESET}  {DIM}last time: {timing}{RESET}\r\n"
+            );
+        }
+        let _ = write!(out, "\r\n{}\r\n", self.message);
+        out
+    }
+}
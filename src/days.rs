@@ -0,0 +1,104 @@
+//! Registry of all implemented days, of every year.
+//!
+//! Tooling that works on any day (differential testing, minimizing, ...) looks the
+//! solvers up here rather than naming the day modules directly.
+
+use crate::{config::Config, oracle, y2023};
+
+/// A solver for one part of a day
+pub type Solver = fn(&str) -> usize;
+
+pub struct Day {
+    pub year: usize,
+    pub day: usize,
+    /// The actual solutions for part 1 and 2 (if they're done)
+    pub solvers: [Option<Solver>; 2],
+    /// Brute-force reference implementations to check the solvers against
+    pub oracles: [Option<Solver>; 2],
+}
+
+pub static DAYS: [Day; 5] = [
+    // 2023
+    Day {
+        year: 2023,
+        day: 1,
+        solvers: [Some(y2023::day_01::fst), Some(y2023::day_01::snd)],
+        oracles: [
+            Some(oracle::y2023::day_01::fst),
+            Some(oracle::y2023::day_01::snd),
+        ],
+    },
+    Day {
+        year: 2023,
+        day: 2,
+        solvers: [Some(y2023::day_02::fst), Some(y2023::day_02::snd)],
+        oracles: [
+            Some(oracle::y2023::day_02::fst),
+            Some(oracle::y2023::day_02::snd),
+        ],
+    },
+    Day {
+        year: 2023,
+        day: 3,
+        solvers: [Some(y2023::day_03::fst), Some(y2023::day_03::snd)],
+        oracles: [
+            Some(oracle::y2023::day_03::fst),
+            Some(oracle::y2023::day_03::snd),
+        ],
+    },
+    Day {
+        year: 2023,
+        day: 4,
+        solvers: [Some(y2023::day_04::fst), Some(y2023::day_04::snd)],
+        oracles: [
+            Some(oracle::y2023::day_04::fst),
+            Some(oracle::y2023::day_04::snd),
+        ],
+    },
+    Day {
+        year: 2023,
+        day: 5,
+        solvers: [Some(y2023::day_05::fst), Some(y2023::day_05::snd)],
+        oracles: [
+            Some(oracle::y2023::day_05::fst),
+            Some(oracle::y2023::day_05::snd),
+        ],
+    },
+];
+
+/// Look up a day by its year and number
+pub fn get(year: usize, day: usize) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// The implemented days of some year
+pub fn of_year(year: usize) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
+
+/// The most recent year with any implemented days, which the binaries default to
+pub fn latest_year() -> usize {
+    DAYS.iter().map(|d| d.year).max().unwrap()
+}
+
+/// The year the binaries work on without `--year`: the one in the
+/// [config](crate::config), or else the [latest one](latest_year)
+pub fn default_year() -> usize {
+    Config::get().year.unwrap_or_else(latest_year)
+}
+
+/// Take a `--year YEAR` flag out of some command line arguments, defaulting to the
+/// [default year](default_year)
+pub fn take_year(args: &mut Vec<String>) -> usize {
+    match args.iter().position(|arg| arg == "--year") {
+        Some(pos) => {
+            let year = args
+                .get(pos + 1)
+                .and_then(|year| year.parse().ok())
+                .expect("--year needs a year");
+            args.drain(pos..pos + 2);
+            year
+        }
+        None => default_year(),
+    }
+}
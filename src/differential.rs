@@ -0,0 +1,79 @@
+//! Differential testing of the solvers against the brute-force [oracles](crate::oracle).
+
+use std::{any::Any, fmt, panic};
+
+use crate::days::{self, Solver};
+
+/// A part where the solver and the oracle disagree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub solver: Outcome,
+    pub oracle: Outcome,
+}
+
+/// The result of running a solver (or oracle) on some input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(usize),
+    Panic(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Panic(msg) => write!(f, "panic ({msg})"),
+        }
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {} part {}: solver says {} but oracle says {}",
+            self.year, self.day, self.part, self.solver, self.oracle
+        )
+    }
+}
+
+/// Run a solver, catching any panics
+pub fn run(solver: Solver, input: &str) -> Outcome {
+    match panic::catch_unwind(|| solver(input)) {
+        Ok(answer) => Outcome::Answer(answer),
+        Err(payload) => Outcome::Panic(panic_message(&*payload)),
+    }
+}
+
+/// Get the message out of a caught panic
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+/// Compare all finished solvers of a day against their oracles on some input
+pub fn compare(year: usize, day: usize, input: &str) -> Vec<Mismatch> {
+    let day = days::get(year, day).unwrap_or_else(|| panic!("{year} day {day} isn't implemented"));
+    day.solvers
+        .iter()
+        .zip(&day.oracles)
+        .zip(1..)
+        .filter_map(|((solver, oracle), part)| {
+            let solver = run((*solver)?, input);
+            let oracle = run((*oracle)?, input);
+            (solver != oracle).then_some(Mismatch {
+                year: day.year,
+                day: day.day,
+                part,
+                solver,
+                oracle,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,331 @@
+//! Fuzz targets for the parsers and solvers.
+//!
+//! Every target gets the raw bytes (decoded lossily, as the parsers take `&str`):
+//!
+//! * the parsers report malformed input through their `FromStr` errors, so they must not
+//!   panic on anything
+//! * the solvers can't report errors, so for them the [input schemas](crate::validate)
+//!   are the contract: they must not panic on anything the schema doesn't reject with an
+//!   error. Inputs with schema errors are what the solution explicitly can't handle and
+//!   are skipped.
+//!
+//! Inputs are produced by mutating generated ones (see [`mutate`]) so that a good share
+//! of them is close enough to a real input to get deep into the code.
+
+use std::{
+    fs, io, panic,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    days,
+    differential::panic_message,
+    generate,
+    rng::Rng,
+    validate::{self, Severity},
+    y2023::{day_01, day_02, day_03, day_04, day_05},
+};
+
+pub struct Target {
+    pub name: &'static str,
+    pub year: usize,
+    pub day: usize,
+    pub run: fn(&[u8]),
+}
+
+pub static TARGETS: [Target; 15] = [
+    Target {
+        name: "y2023_day_01_parse",
+        year: 2023,
+        day: 1,
+        run: |bytes| {
+            parse(bytes, |input| {
+                input
+                    .lines()
+                    .for_each(|line| day_01::parse_digits(line).for_each(drop))
+            })
+        },
+    },
+    Target {
+        name: "y2023_day_01_fst",
+        year: 2023,
+        day: 1,
+        run: |bytes| solve(2023, 1, 0, bytes),
+    },
+    Target {
+        name: "y2023_day_01_snd",
+        year: 2023,
+        day: 1,
+        run: |bytes| solve(2023, 1, 1, bytes),
+    },
+    Target {
+        name: "y2023_day_02_parse",
+        year: 2023,
+        day: 2,
+        run: |bytes| {
+            parse(bytes, |input| {
+                input.lines().for_each(|line| {
+                    day_02::Game::from_str(line)
+                        .map(|game| game.minimal_possible_bag())
+                        .ok();
+                })
+            })
+        },
+    },
+    Target {
+        name: "y2023_day_02_fst",
+        year: 2023,
+        day: 2,
+        run: |bytes| solve(2023, 2, 0, bytes),
+    },
+    Target {
+        name: "y2023_day_02_snd",
+        year: 2023,
+        day: 2,
+        run: |bytes| solve(2023, 2, 1, bytes),
+    },
+    Target {
+        name: "y2023_day_03_parse",
+        year: 2023,
+        day: 3,
+        run: |bytes| {
+            parse(bytes, |input| {
+                day_03::Schematic::from_str(input)
+                    .map(|schematic| schematic.adjacencies().len())
+                    .ok();
+            })
+        },
+    },
+    Target {
+        name: "y2023_day_03_fst",
+        year: 2023,
+        day: 3,
+        run: |bytes| solve(2023, 3, 0, bytes),
+    },
+    Target {
+        name: "y2023_day_03_snd",
+        year: 2023,
+        day: 3,
+        run: |bytes| solve(2023, 3, 1, bytes),
+    },
+    Target {
+        name: "y2023_day_04_parse",
+        year: 2023,
+        day: 4,
+        run: |bytes| {
+            parse(bytes, |input| {
+                input.lines().for_each(|line| {
+                    day_04::Card::from_str(line)
+                        .map(|card| card.count_wins())
+                        .ok();
+                })
+            })
+        },
+    },
+    Target {
+        name: "y2023_day_04_fst",
+        year: 2023,
+        day: 4,
+        run: |bytes| solve(2023, 4, 0, bytes),
+    },
+    Target {
+        name: "y2023_day_04_snd",
+        year: 2023,
+        day: 4,
+        run: |bytes| solve(2023, 4, 1, bytes),
+    },
+    Target {
+        name: "y2023_day_05_parse",
+        year: 2023,
+        day: 5,
+        run: |bytes| {
+            parse(bytes, |input| {
+                day_05::Almanac::from_str(input).ok();
+            })
+        },
+    },
+    Target {
+        name: "y2023_day_05_fst",
+        year: 2023,
+        day: 5,
+        run: |bytes| solve(2023, 5, 0, bytes),
+    },
+    Target {
+        name: "y2023_day_05_snd",
+        year: 2023,
+        day: 5,
+        run: |bytes| solve(2023, 5, 1, bytes),
+    },
+];
+
+/// Look up a target by its name
+pub fn target(name: &str) -> Option<&'static Target> {
+    TARGETS.iter().find(|target| target.name == name)
+}
+
+/// Run a parser on any input
+fn parse(bytes: &[u8], parser: impl Fn(&str)) {
+    parser(&String::from_utf8_lossy(bytes))
+}
+
+/// Run a solver on any input its schema doesn't reject with an error (warnings are fine,
+/// the solver has to cope with them)
+fn solve(year: usize, day: usize, part: usize, bytes: &[u8]) {
+    let input = String::from_utf8_lossy(bytes);
+    let day = days::get(year, day).unwrap();
+    let schema = validate::schema(year, day.day).unwrap();
+    let Some(solver) = day.solvers[part] else {
+        return;
+    };
+    if schema
+        .validate(&input)
+        .iter()
+        .all(|violation| violation.severity != Severity::Error)
+    {
+        solver(&input);
+    }
+}
+
+/// Run a target on some input and return the panic message if it crashes
+pub fn crashes(target: &Target, bytes: &[u8]) -> Option<String> {
+    panic::catch_unwind(|| (target.run)(bytes))
+        .err()
+        .map(|payload| panic_message(&*payload))
+}
+
+/// A small generated input for some day to start mutating from
+pub fn seed_input(year: usize, day: usize, rng: &mut Rng) -> Vec<u8> {
+    let input = match (year, day) {
+        (2023, 1) => generate::y2023::day_01::generate(
+            rng,
+            &generate::y2023::day_01::Params {
+                lines: 5,
+                ..Default::default()
+            },
+        ),
+        (2023, 2) => generate::y2023::day_02::generate(
+            rng,
+            &generate::y2023::day_02::Params {
+                games: 5,
+                ..Default::default()
+            },
+        ),
+        (2023, 3) => generate::y2023::day_03::generate(
+            rng,
+            &generate::y2023::day_03::Params {
+                width: 12,
+                height: 6,
+                ..Default::default()
+            },
+        ),
+        (2023, 4) => generate::y2023::day_04::generate(
+            rng,
+            &generate::y2023::day_04::Params {
+                cards: 6,
+                winning: 5,
+                own: 8,
+                win_rate: 0.4,
+                ..Default::default()
+            },
+        ),
+        (2023, 5) => generate::y2023::day_05::generate(
+            rng,
+            &generate::y2023::day_05::Params {
+                seed_pairs: 2,
+                maps: 3,
+                ranges_per_map: 1..=3,
+                max_value: 100,
+            },
+        ),
+        _ => panic!("no fuzz seeds for {year} day {day}"),
+    };
+    input.into_bytes()
+}
+
+/// Bytes that are likely to matter to one of the parsers
+const INTERESTING: &[&[u8]] = &[
+    b"0",
+    b"1",
+    b"9",
+    b" ",
+    b"  ",
+    b"\n",
+    b"\n\n",
+    b":",
+    b"|",
+    b";",
+    b",",
+    b".",
+    b"*",
+    b"#",
+    b"one",
+    b"zero",
+    b"eight",
+    b"Card",
+    b"Game",
+    b"red",
+    b"map:",
+    b"\xc3\xa9",
+    b"\t",
+    b"99999999999999999999",
+];
+
+/// Apply a handful of random mutations to some input
+pub fn mutate(rng: &mut Rng, bytes: &mut Vec<u8>) {
+    for _ in 0..rng.range_inclusive(1..=4) {
+        let pos = rng.range_inclusive(0..=bytes.len());
+        match rng.below(6) {
+            // overwrite a byte
+            0 if pos < bytes.len() => bytes[pos] = rng.below(256) as u8,
+            // delete a few bytes
+            1 if pos < bytes.len() => {
+                let end = (pos + rng.range_inclusive(1..=4)).min(bytes.len());
+                bytes.drain(pos..end);
+            }
+            // duplicate a line
+            2 => {
+                let line = bytes
+                    .split(|&b| b == b'\n')
+                    .nth(rng.below(bytes.iter().filter(|&&b| b == b'\n').count() + 1))
+                    .unwrap_or_default()
+                    .to_vec();
+                bytes.splice(pos..pos, line.into_iter().chain(*b"\n"));
+            }
+            // swap two bytes
+            3 if !bytes.is_empty() => {
+                let (pos, other) = (pos.min(bytes.len() - 1), rng.below(bytes.len()));
+                bytes.swap(pos, other);
+            }
+            // truncate
+            4 => bytes.truncate(pos),
+            // insert something interesting
+            _ => {
+                let token = rng.choose(INTERESTING);
+                bytes.splice(pos..pos, token.iter().copied());
+            }
+        }
+    }
+}
+
+/// Where crashing inputs get stored so they're replayed by the regression tests
+pub fn regression_dir(target: &Target) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("regressions")
+        .join(target.name)
+}
+
+/// Store a crashing input as a regression case (named by its hash so that the same crash
+/// isn't stored twice)
+pub fn save_crash(target: &Target, bytes: &[u8]) -> io::Result<PathBuf> {
+    // FNV-1a
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    let dir = regression_dir(target);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{hash:016x}.txt"));
+    fs::write(&path, bytes)?;
+    Ok(path)
+}
@@ -0,0 +1,22 @@
+//! Random puzzle input generators.
+//!
+//! Every implemented day has a generator producing inputs in the same format as the real
+//! puzzle inputs, together with a `Params` struct to tune their size and shape. The
+//! generators are deterministic for a given [`Rng`] seed, so a failing input can always be
+//! reproduced from its seed.
+
+pub mod y2023;
+
+pub use crate::rng::Rng;
+
+/// Generate an input with default parameters for some day
+pub fn generate(year: usize, day: usize, rng: &mut Rng) -> Option<String> {
+    match (year, day) {
+        (2023, 1) => Some(y2023::day_01::generate(rng, &Default::default())),
+        (2023, 2) => Some(y2023::day_02::generate(rng, &Default::default())),
+        (2023, 3) => Some(y2023::day_03::generate(rng, &Default::default())),
+        (2023, 4) => Some(y2023::day_04::generate(rng, &Default::default())),
+        (2023, 5) => Some(y2023::day_05::generate(rng, &Default::default())),
+        _ => None,
+    }
+}
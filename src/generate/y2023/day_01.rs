@@ -0,0 +1,78 @@
+use crate::rng::Rng;
+
+const NUMERALS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Numerals whose last letter starts another numeral, e.g. `eightwo` or `oneight`
+const OVERLAPPING: [&str; 10] = [
+    "oneight",
+    "twone",
+    "threeight",
+    "fiveight",
+    "sevenine",
+    "eightwo",
+    "eighthree",
+    "nineight",
+    "eightwone",
+    "sevenineight",
+];
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub lines: usize,
+    /// Number of tokens (digits, numerals or junk letters) per line
+    pub tokens_per_line: std::ops::RangeInclusive<usize>,
+    pub digit_rate: f64,
+    pub numeral_rate: f64,
+    /// Probability of a numeral token being one of the overlapping ones
+    pub overlap_rate: f64,
+    /// Make sure every line has an ascii digit so that part 1 can handle it
+    pub require_digit: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            lines: 1000,
+            tokens_per_line: 1..=12,
+            digit_rate: 0.2,
+            numeral_rate: 0.2,
+            overlap_rate: 0.3,
+            require_digit: true,
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    (0..params.lines)
+        .map(|_| line(rng, params))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn line(rng: &mut Rng, params: &Params) -> String {
+    let mut line = String::new();
+    let mut has_digit = false;
+    for _ in 0..rng.range_inclusive(params.tokens_per_line.clone()) {
+        let p = rng.unit();
+        if p < params.digit_rate {
+            line.push(char::from(b'1' + rng.below(9) as u8));
+            has_digit = true;
+        } else if p < params.digit_rate + params.numeral_rate {
+            let numeral = if rng.chance(params.overlap_rate) {
+                rng.choose(&OVERLAPPING)
+            } else {
+                rng.choose(&NUMERALS)
+            };
+            line.push_str(numeral);
+        } else {
+            line.push(char::from(b'a' + rng.below(26) as u8));
+        }
+    }
+    if params.require_digit && !has_digit {
+        let digit = char::from(b'1' + rng.below(9) as u8);
+        line.insert(rng.range_inclusive(0..=line.len()), digit);
+    }
+    line
+}
@@ -0,0 +1,44 @@
+use crate::rng::Rng;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub games: usize,
+    pub draws: std::ops::RangeInclusive<usize>,
+    pub max_cubes: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            games: 100,
+            draws: 1..=6,
+            max_cubes: 20,
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    (1..=params.games)
+        .map(|game_id| {
+            let draws = (0..rng.range_inclusive(params.draws.clone()))
+                .map(|_| draw(rng, params))
+                .collect::<Vec<_>>()
+                .join("; ");
+            format!("Game {game_id}: {draws}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A draw shows each color at most once and at least one color
+fn draw(rng: &mut Rng, params: &Params) -> String {
+    let mut colors = COLORS;
+    rng.shuffle(&mut colors);
+    colors[..rng.range_inclusive(1..=3)]
+        .iter()
+        .map(|color| format!("{} {color}", rng.range_inclusive(1..=params.max_cubes)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,58 @@
+use crate::rng::Rng;
+
+const SYMBOLS: &[char] = &['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'];
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub width: usize,
+    pub height: usize,
+    /// Probability of a cell being a symbol
+    pub symbol_density: f64,
+    /// Probability of a number starting at a free cell
+    pub number_density: f64,
+    /// Probability of a symbol being a `*` (and hence a potential gear)
+    pub gear_rate: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            width: 140,
+            height: 140,
+            symbol_density: 0.05,
+            number_density: 0.1,
+            gear_rate: 0.3,
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    (0..params.height)
+        .map(|_| line(rng, params))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn line(rng: &mut Rng, params: &Params) -> String {
+    let mut line = String::with_capacity(params.width);
+    while line.len() < params.width {
+        if rng.chance(params.symbol_density) {
+            line.push(if rng.chance(params.gear_rate) {
+                '*'
+            } else {
+                *rng.choose(SYMBOLS)
+            });
+        } else if rng.chance(params.number_density) {
+            let digits = rng.range_inclusive(1..=3).min(params.width - line.len());
+            let num = rng.range(10usize.pow(digits as u32 - 1)..10usize.pow(digits as u32));
+            line.push_str(&num.to_string());
+            // numbers are always separated by at least one other cell
+            if line.len() < params.width {
+                line.push('.');
+            }
+        } else {
+            line.push('.');
+        }
+    }
+    line
+}
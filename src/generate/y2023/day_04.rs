@@ -0,0 +1,66 @@
+use crate::rng::Rng;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub cards: usize,
+    pub winning: usize,
+    pub own: usize,
+    /// Probability of each of our own numbers being a winning one
+    pub win_rate: f64,
+    /// The numbers on the cards are drawn from `1..=max_num`
+    pub max_num: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            cards: 200,
+            winning: 10,
+            own: 25,
+            win_rate: 0.15,
+            max_num: 99,
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(
+        params.winning + params.own <= params.max_num,
+        "not enough distinct numbers for a card"
+    );
+    let id_width = params.cards.to_string().len();
+    let num_width = params.max_num.to_string().len();
+    (1..=params.cards)
+        .map(|id| {
+            let mut pool = (1..=params.max_num).collect::<Vec<_>>();
+            rng.shuffle(&mut pool);
+            let (winning, rest) = pool.split_at(params.winning);
+            // cards never make us copy cards past the end of the table
+            let max_wins = params.own.min(params.winning).min(params.cards - id);
+            let mut wins = 0;
+            let mut rest = rest.iter();
+            let own = (0..params.own)
+                .map(|_| {
+                    if wins < max_wins && rng.chance(params.win_rate) {
+                        wins += 1;
+                        winning[wins - 1]
+                    } else {
+                        *rest.next().unwrap()
+                    }
+                })
+                .collect::<Vec<_>>();
+            let fmt_nums = |nums: &[usize]| {
+                nums.iter()
+                    .map(|n| format!("{n:>num_width$}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            format!(
+                "Card {id:>id_width$}: {} | {}",
+                fmt_nums(winning),
+                fmt_nums(&own)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,100 @@
+use crate::rng::Rng;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Number of (start, length) seed pairs
+    pub seed_pairs: usize,
+    /// Number of maps between seeds and locations
+    pub maps: usize,
+    pub ranges_per_map: std::ops::RangeInclusive<usize>,
+    /// All values (seeds, range starts and ends) are below this
+    pub max_value: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            seed_pairs: 10,
+            maps: 7,
+            ranges_per_map: 1..=10,
+            max_value: 1000,
+        }
+    }
+}
+
+/// Category names for a chain of `maps` maps
+fn categories(maps: usize) -> Vec<String> {
+    if maps == CATEGORIES.len() - 1 {
+        CATEGORIES.iter().map(|c| c.to_string()).collect()
+    } else {
+        std::iter::once("seed".to_string())
+            .chain((1..maps).map(|i| format!("category{i}")))
+            .chain(std::iter::once("location".to_string()))
+            .collect()
+    }
+}
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let seeds = (0..params.seed_pairs)
+        .flat_map(|_| {
+            let start = rng.below(params.max_value);
+            let len = rng.range_inclusive(1..=params.max_value - start);
+            [start, len]
+        })
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let categories = categories(params.maps);
+    let maps = categories
+        .windows(2)
+        .map(|pair| {
+            let ranges = ranges(rng, params)
+                .into_iter()
+                .map(|(dest, source, len)| format!("{dest} {source} {len}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("{}-to-{} map:\n{ranges}", pair[0], pair[1])
+        })
+        .collect::<Vec<_>>();
+    std::iter::once(format!("seeds: {seeds}"))
+        .chain(maps)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Random `(dest_start, source_start, len)` triples with non-overlapping sources
+fn ranges(rng: &mut Rng, params: &Params) -> Vec<(usize, usize, usize)> {
+    let count = rng
+        .range_inclusive(params.ranges_per_map.clone())
+        .min(params.max_value / 2)
+        .max(1);
+    // cut `[0, max_value)` at random points and use every other interval as a source range
+    let mut cuts = (0..2 * count)
+        .map(|_| rng.below(params.max_value))
+        .collect::<Vec<_>>();
+    cuts.sort_unstable();
+    cuts.dedup();
+    let mut ranges = cuts
+        .chunks_exact(2)
+        .map(|pair| {
+            let (source, len) = (pair[0], pair[1] - pair[0]);
+            (rng.below(params.max_value - len), source, len)
+        })
+        .collect::<Vec<_>>();
+    if ranges.is_empty() {
+        ranges.push((rng.below(params.max_value - 1), 0, 1));
+    }
+    rng.shuffle(&mut ranges);
+    ranges
+}
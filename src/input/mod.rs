@@ -1,5 +1,143 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
+//! The puzzle inputs.
+//!
+//! The examples of part 1 from the puzzle texts are in the `day_XX` modules (all of
+//! them, along with their answers, are [extracted from the puzzle pages](crate::puzzle));
+//! the actual inputs are stored [encrypted](crate::crypt) in the
+//! [profile](crate::profile) directories in `inputs` and decrypted on load.
+//!
+//! Instead of a stored input the binaries can also be given a file on the command line,
+//! or `-` to read one from stdin (see [`from_cli`]). Stdin is only ever read when asked
+//! to, so that running a binary from a script or an editor doesn't block on it.
+//!
+//! With the `embed` feature the build script instead decrypts the inputs at build time and
+//! compiles them into the binary [compressed](crate::lz). They're decompressed the first
+//! time they're loaded.
+
+use std::{
+    fs,
+    io::{self, BufRead},
+    path::PathBuf,
+};
+
+use crate::{
+    config::Config,
+    crypt::{self, Key},
+    profile::Profile,
+};
+
+pub mod y2023;
+
+/// The directory the profiles with their encrypted inputs are stored in
+pub fn dir() -> PathBuf {
+    Config::get().inputs_dir()
+}
+
+/// Where the encrypted input of a profile for some day is stored
+pub fn path(profile: &Profile, year: usize, day: usize) -> PathBuf {
+    profile.year_dir(year).join(format!("day_{day:02}.txt.enc"))
+}
+
+#[cfg(feature = "embed")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+}
+
+/// The profiles that have embedded inputs
+#[cfg(feature = "embed")]
+pub(crate) fn embedded_profiles() -> impl Iterator<Item = &'static str> {
+    embedded::INPUTS.iter().map(|(profile, _, _, _)| *profile)
+}
+
+/// The plain text of an embedded input of the profile the build was for (`AOC_PROFILE`
+/// at build time), which unlike the others is usable in `const` context. Only the days
+/// that are [solved at compile time](crate::precomputed) have one.
+#[cfg(feature = "embed")]
+pub const fn embedded_plain(year: usize, day: usize) -> Option<&'static str> {
+    embedded::plain(year, day)
+}
+
+/// Get an embedded input, decompressing it if it's the first time it's used
+#[cfg(feature = "embed")]
+fn embedded(profile: &Profile, year: usize, day: usize) -> Option<&'static str> {
+    use once_cell::sync::{Lazy, OnceCell};
+
+    static DECOMPRESSED: Lazy<Vec<OnceCell<String>>> =
+        Lazy::new(|| embedded::INPUTS.iter().map(|_| OnceCell::new()).collect());
+    let idx = embedded::INPUTS
+        .iter()
+        .position(|(p, y, d, _)| *p == profile.name() && *y == year && *d == day)?;
+    let input = DECOMPRESSED[idx].get_or_init(|| {
+        String::from_utf8(crate::lz::decompress(embedded::INPUTS[idx].3).unwrap()).unwrap()
+    });
+    Some(input)
+}
+
+/// Load and decrypt the input of a profile for some day
+pub fn try_load(profile: &Profile, year: usize, day: usize) -> Result<String, crypt::Error> {
+    #[cfg(feature = "embed")]
+    if let Some(input) = embedded(profile, year, day) {
+        return Ok(input.to_string());
+    }
+
+    let key = Key::load()?;
+    let plaintext = crypt::decrypt(&key, &fs::read(path(profile, year, day))?)?;
+    String::from_utf8(plaintext).map_err(|_| crypt::Error::Corrupted)
+}
+
+/// Load and decrypt the input of the current profile for some day, panicking with a
+/// (hopefully) helpful message if that's not possible
+pub fn load(year: usize, day: usize) -> String {
+    let profile = Profile::current();
+    try_load(&profile, year, day).unwrap_or_else(|err| {
+        panic!("can't load the input of {profile} for {year} day {day}: {err}")
+    })
+}
+
+/// Read an input from any reader.
+///
+/// Line endings get normalized and trailing newlines stripped so that the solutions see
+/// exactly what they'd see for a stored input.
+pub fn from_reader(reader: impl BufRead) -> io::Result<String> {
+    let lines = reader.lines().collect::<io::Result<Vec<_>>>()?;
+    let mut input = lines.join("\n");
+    input.truncate(input.trim_end_matches('\n').len());
+    Ok(input)
+}
+
+/// Where to take an input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    Stored(Profile),
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, year: usize, day: usize) -> Result<String, crypt::Error> {
+        match self {
+            Source::Stdin => Ok(from_reader(io::stdin().lock())?),
+            Source::File(path) => Ok(from_reader(io::BufReader::new(fs::File::open(path)?))?),
+            Source::Stored(profile) => try_load(profile, year, day),
+        }
+    }
+}
+
+/// The input for a binary: the file given as the first argument (`-` for stdin), or the
+/// stored input of the current profile
+pub fn from_cli(year: usize, day: usize) -> String {
+    let source = match std::env::args().nth(1) {
+        Some(arg) => Source::from_arg(&arg),
+        None => Source::Stored(Profile::current()),
+    };
+    source
+        .read(year, day)
+        .unwrap_or_else(|err| panic!("can't read the input for {year} day {day}: {err}"))
+}
@@ -0,0 +1 @@
+pub static TEST_INPUT: &str = include_str!(concat!(env!("OUT_DIR"), "/example_2023_day_02.txt"));
@@ -0,0 +1,7 @@
+//! The examples for 2023: the example of part 1 from each day's
+//! `puzzles/2023/day_XX/examples.txt`, copied out by the build script.
+
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
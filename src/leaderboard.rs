@@ -0,0 +1,357 @@
+//! Analytics for a private leaderboard, from the JSON export of its page.
+//!
+//! The site only ranks by its local score, which mostly measures who's awake when the
+//! puzzles unlock. This recomputes the rankings under some [alternative rules](Scoring)
+//! and looks at how long everyone took on each day, working entirely from a saved export.
+
+use std::{cmp::Reverse, collections::BTreeMap, fmt, str::FromStr};
+
+use itertools::Itertools;
+use serde::{de, Deserialize, Deserializer};
+
+use crate::progress::format_seconds;
+
+/// How the members get ranked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scoring {
+    /// The site's own: for every star the first of N members gets N points, the second
+    /// N - 1 and so on
+    Local,
+    /// Most stars, ties going to whoever got their last one first
+    Stars,
+    /// Most stars, ties going to the least total time from unlock to star
+    Time,
+    /// Like `Local`, but only for second stars and ordered by the time between the two
+    /// stars of a day - so starting late doesn't matter
+    Delta,
+}
+
+impl FromStr for Scoring {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(Self::Local),
+            "stars" => Ok(Self::Stars),
+            "time" => Ok(Self::Time),
+            "delta" => Ok(Self::Delta),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    /// Seconds since the unix epoch
+    pub get_star_ts: u64,
+    /// Breaks ties between stars gotten in the same second
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Anonymous users don't have one
+    pub name: Option<String>,
+    pub stars: usize,
+    pub local_score: u64,
+    /// Day to part to star, both keyed by strings in the export
+    #[serde(deserialize_with = "stars")]
+    completion_day_level: BTreeMap<usize, BTreeMap<usize, Star>>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star(&self, day: usize, part: usize) -> Option<Star> {
+        self.completion_day_level.get(&day)?.get(&part).copied()
+    }
+
+    fn all_stars(&self) -> impl Iterator<Item = ((usize, usize), Star)> + '_ {
+        self.completion_day_level
+            .iter()
+            .flat_map(|(&day, parts)| parts.iter().map(move |(&part, star)| ((day, part), *star)))
+    }
+}
+
+/// The stars of a member, which have to be on one of the 25 days and one of its 2 parts
+fn stars<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<usize, BTreeMap<usize, Star>>, D::Error> {
+    let stars = BTreeMap::<usize, BTreeMap<usize, Star>>::deserialize(deserializer)?;
+    for (day, parts) in &stars {
+        if !(1..=25).contains(day) {
+            return Err(de::Error::custom(format!("invalid day {day}")));
+        }
+        if let Some(part) = parts.keys().find(|part| !(1..=2).contains(*part)) {
+            return Err(de::Error::custom(format!(
+                "invalid part {part} of day {day}"
+            )));
+        }
+    }
+    Ok(stars)
+}
+
+/// The event, which has to be a year
+fn year<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let event = String::deserialize(deserializer)?;
+    match event.parse::<u16>() {
+        Ok(_) => Ok(event),
+        Err(_) => Err(de::Error::custom(format!(
+            "invalid event {event:?}, expected a year"
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    /// The year
+    #[serde(deserialize_with = "year")]
+    pub event: String,
+    pub owner_id: u64,
+    members: BTreeMap<String, Member>,
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+impl FromStr for Leaderboard {
+    type Err = serde_json::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+impl Leaderboard {
+    pub fn year(&self) -> i64 {
+        // checked when parsing
+        self.event.parse::<u16>().map_or(0, i64::from)
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &Member> {
+        self.members.values()
+    }
+
+    /// When a day's puzzle unlocks: midnight in UTC-5
+    pub fn unlock(&self, day: usize) -> u64 {
+        (days_from_civil(self.year(), 12, day as i64) * 86400 + 5 * 3600) as u64
+    }
+
+    /// Seconds from a day's unlock until a member got a star
+    pub fn completion_time(&self, member: &Member, day: usize, part: usize) -> Option<u64> {
+        let star = member.star(day, part)?;
+        Some(star.get_star_ts.saturating_sub(self.unlock(day)))
+    }
+
+    /// Seconds between a member's two stars of a day
+    pub fn delta(&self, member: &Member, day: usize) -> Option<u64> {
+        let (fst, snd) = (member.star(day, 1)?, member.star(day, 2)?);
+        Some(snd.get_star_ts.saturating_sub(fst.get_star_ts))
+    }
+
+    /// The last day anyone has a star on
+    pub fn last_day(&self) -> usize {
+        self.members()
+            .flat_map(Member::all_stars)
+            .map(|((day, _), _)| day)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Hand out `N`, `N - 1`, ... points to the members in the given order
+    fn award<'a>(
+        &self,
+        points: &mut BTreeMap<u64, u64>,
+        ordered: impl Iterator<Item = &'a Member>,
+    ) {
+        let n = self.members.len() as u64;
+        for (rank, member) in ordered.enumerate() {
+            *points.entry(member.id).or_default() += n - rank as u64;
+        }
+    }
+
+    /// The members from first to last place along with their score - points for
+    /// [`Scoring::Local`] and [`Scoring::Delta`], stars for [`Scoring::Stars`] and
+    /// seconds for [`Scoring::Time`]
+    pub fn rankings(&self, scoring: Scoring) -> Vec<(&Member, u64)> {
+        match scoring {
+            Scoring::Local | Scoring::Delta => {
+                let mut points = self.members().map(|m| (m.id, 0)).collect();
+                for day in 1..=25 {
+                    if scoring == Scoring::Delta {
+                        let ordered = self
+                            .members()
+                            .filter_map(|m| Some((self.delta(m, day)?, m)))
+                            .sorted_by_key(|&(delta, m)| {
+                                let star = m.star(day, 2).unwrap();
+                                (delta, star.get_star_ts, star.star_index)
+                            })
+                            .map(|(_, m)| m);
+                        self.award(&mut points, ordered);
+                        continue;
+                    }
+                    for part in 1..=2 {
+                        let ordered = self
+                            .members()
+                            .filter_map(|m| m.star(day, part).map(|star| (star, m)))
+                            .sorted_by_key(|&(star, _)| (star.get_star_ts, star.star_index))
+                            .map(|(_, m)| m);
+                        self.award(&mut points, ordered);
+                    }
+                }
+                self.members()
+                    .map(|m| (m, points[&m.id]))
+                    .sorted_by_key(|&(m, points)| (Reverse(points), m.id))
+                    .collect()
+            }
+            Scoring::Stars => self
+                .members()
+                .sorted_by_key(|m| {
+                    let last = m.all_stars().map(|(_, star)| star.get_star_ts).max();
+                    (Reverse(m.stars), last, m.id)
+                })
+                .map(|m| (m, m.stars as u64))
+                .collect(),
+            Scoring::Time => self
+                .members()
+                .map(|m| {
+                    let total = m
+                        .all_stars()
+                        .map(|((day, part), _)| self.completion_time(m, day, part).unwrap())
+                        .sum::<u64>();
+                    (m, total)
+                })
+                .sorted_by_key(|&(m, total)| (Reverse(m.stars), total, m.id))
+                .collect(),
+        }
+    }
+
+    pub fn rankings_table(&self, scoring: Scoring) -> Table {
+        let score = match scoring {
+            Scoring::Local | Scoring::Delta => "points",
+            Scoring::Stars => "stars",
+            Scoring::Time => "total time",
+        };
+        Table {
+            headers: vec!["rank".into(), "name".into(), score.into(), "stars".into()],
+            rows: self
+                .rankings(scoring)
+                .into_iter()
+                .enumerate()
+                .map(|(rank, (member, score))| {
+                    let score = match scoring {
+                        Scoring::Time => format_seconds(Some(score)),
+                        _ => score.to_string(),
+                    };
+                    vec![
+                        (rank + 1).to_string(),
+                        member.display_name(),
+                        score,
+                        member.stars.to_string(),
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    /// How long every member took on every day, along with the deltas between the parts
+    pub fn times_table(&self) -> Table {
+        Table {
+            headers: ["day", "name", "part 1", "part 2", "delta"]
+                .map(String::from)
+                .to_vec(),
+            rows: (1..=self.last_day())
+                .flat_map(|day| {
+                    self.members()
+                        .filter(move |m| m.star(day, 1).is_some())
+                        .sorted_by_key(move |m| self.completion_time(m, day, 1))
+                        .map(move |m| {
+                            vec![
+                                day.to_string(),
+                                m.display_name(),
+                                format_seconds(self.completion_time(m, day, 1)),
+                                format_seconds(self.completion_time(m, day, 2)),
+                                format_seconds(self.delta(m, day)),
+                            ]
+                        })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Some rows of results, printable as an aligned table or as CSV
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Quote a CSV field if it needs it
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+impl Table {
+    pub fn to_csv(&self) -> String {
+        std::iter::once(&self.headers)
+            .chain(&self.rows)
+            .map(|row| row.iter().map(|s| csv_field(s)).join(",") + "\n")
+            .collect()
+    }
+}
+
+impl fmt::Display for Table {
+    /// Left aligned columns of text with everything else right aligned
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = (0..self.headers.len())
+            .map(|col| {
+                std::iter::once(&self.headers)
+                    .chain(&self.rows)
+                    .map(|row| row[col].chars().count())
+                    .max()
+                    .unwrap()
+            })
+            .collect_vec();
+        let numeric = (0..self.headers.len())
+            .map(|col| {
+                self.rows.iter().all(|row| {
+                    row[col]
+                        .chars()
+                        .all(|c| c.is_ascii_digit() || c == ':' || c == '-')
+                })
+            })
+            .collect_vec();
+        for row in std::iter::once(&self.headers).chain(&self.rows) {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .zip(&numeric)
+                .map(|((cell, &width), &numeric)| {
+                    if numeric {
+                        format!("{cell:>width$}")
+                    } else {
+                        format!("{cell:<width$}")
+                    }
+                })
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
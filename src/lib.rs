@@ -1 +1,31 @@
+pub mod answers;
+#[cfg(feature = "capi")]
+pub mod capi;
+pub mod check;
+pub mod config;
+pub mod crypt;
+pub mod dashboard;
+pub mod days;
+pub mod differential;
+pub mod fuzz;
+pub mod generate;
 pub mod input;
+pub mod leaderboard;
+pub mod lz;
+pub mod minimize;
+pub mod oracle;
+pub mod plugin;
+#[cfg(feature = "embed")]
+pub mod precomputed;
+pub mod profile;
+pub mod progress;
+pub mod puzzle;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod repl;
+pub mod rng;
+pub mod snapshot;
+pub mod validate;
+pub mod visualize;
+// the solutions themselves are in the `no_std` core crate
+pub use aoc_core::y2023;
@@ -0,0 +1,138 @@
+//! A small LZSS compressor.
+//!
+//! Used to embed the puzzle inputs into the binaries (see the `embed` feature). The
+//! format is deliberately simple: a little endian `u32` with the uncompressed length,
+//! followed by groups of up to eight items each preceded by a flag byte. A cleared flag
+//! bit means the item is a literal byte, a set one that it's a back reference encoded in
+//! two bytes: a 12 bit offset (minus one) followed by a 4 bit length (minus
+//! [`MIN_MATCH`]).
+//!
+//! This module is also compiled into the build script, so it must not depend on
+//! anything but `std`.
+
+use std::collections::HashMap;
+
+const WINDOW: usize = 1 << 12;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = MIN_MATCH + 15;
+/// How many earlier positions with the same prefix we look at when searching for a match
+const MAX_CHAIN: usize = 256;
+
+/// The longest match for the data at `pos` in the window before it as `(offset, len)`
+fn longest_match(data: &[u8], pos: usize, chain: &[usize], head: usize) -> (usize, usize) {
+    let mut best = (0, 0);
+    let mut candidate = head;
+    for _ in 0..MAX_CHAIN {
+        if candidate == usize::MAX || pos - candidate > WINDOW {
+            break;
+        }
+        let len = data[candidate..]
+            .iter()
+            .zip(&data[pos..])
+            .take(MAX_MATCH)
+            .take_while(|(a, b)| a == b)
+            .count();
+        if len > best.1 {
+            best = (pos - candidate, len);
+            if len == MAX_MATCH {
+                break;
+            }
+        }
+        candidate = chain[candidate];
+    }
+    best
+}
+
+/// Remembers the positions of earlier 3 byte prefixes: `heads` holds the most recent
+/// position for each prefix and `chain` the previous one with the same prefix for each
+/// position
+struct Positions {
+    heads: HashMap<[u8; MIN_MATCH], usize>,
+    chain: Vec<usize>,
+}
+
+impl Positions {
+    fn insert(&mut self, data: &[u8], pos: usize) {
+        if let Some(prefix) = data[pos..].first_chunk::<MIN_MATCH>() {
+            self.chain[pos] = self.heads.insert(*prefix, pos).unwrap_or(usize::MAX);
+        }
+    }
+
+    fn head(&self, data: &[u8], pos: usize) -> usize {
+        data[pos..]
+            .first_chunk::<MIN_MATCH>()
+            .and_then(|prefix| self.heads.get(prefix).copied())
+            .unwrap_or(usize::MAX)
+    }
+}
+
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    let mut positions = Positions {
+        heads: HashMap::new(),
+        chain: vec![usize::MAX; data.len()],
+    };
+    let mut out = (data.len() as u32).to_le_bytes().to_vec();
+    let mut flag_idx = 0;
+    let mut item = 8;
+    let mut pos = 0;
+    while pos < data.len() {
+        if item == 8 {
+            flag_idx = out.len();
+            out.push(0);
+            item = 0;
+        }
+        let (offset, len) = longest_match(data, pos, &positions.chain, positions.head(data, pos));
+        if len >= MIN_MATCH {
+            out[flag_idx] |= 1 << item;
+            let code = ((offset - 1) << 4) | (len - MIN_MATCH);
+            out.extend_from_slice(&(code as u16).to_le_bytes());
+            for p in pos..pos + len {
+                positions.insert(data, p);
+            }
+            pos += len;
+        } else {
+            out.push(data[pos]);
+            positions.insert(data, pos);
+            pos += 1;
+        }
+        item += 1;
+    }
+    out
+}
+
+/// Decompress data produced by [`compress`], `None` if it's corrupted or truncated
+pub fn decompress(data: &[u8]) -> Option<Vec<u8>> {
+    let (len, mut data) = data.split_first_chunk::<4>()?;
+    let len = u32::from_le_bytes(*len) as usize;
+    // every input byte makes at most `MAX_MATCH` output bytes, which bounds the
+    // allocation for a corrupted length
+    let mut out = Vec::with_capacity(len.min(data.len() * MAX_MATCH));
+    while out.len() < len {
+        let (&flags, rest) = data.split_first()?;
+        data = rest;
+        for item in 0..8 {
+            if out.len() == len {
+                break;
+            }
+            if flags & (1 << item) == 0 {
+                let (&byte, rest) = data.split_first()?;
+                out.push(byte);
+                data = rest;
+            } else {
+                let (code, rest) = data.split_first_chunk::<2>()?;
+                data = rest;
+                let code = u16::from_le_bytes(*code) as usize;
+                let (offset, match_len) = ((code >> 4) + 1, (code & 0xf) + MIN_MATCH);
+                let start = out.len().checked_sub(offset)?;
+                if out.len() + match_len > len {
+                    return None;
+                }
+                // the match may overlap the data it produces so we copy byte by byte
+                for i in start..start + match_len {
+                    out.push(out[i]);
+                }
+            }
+        }
+    }
+    data.is_empty().then_some(out)
+}
@@ -0,0 +1,153 @@
+//! Shrinking failing inputs via delta debugging.
+//!
+//! A mismatch found on a generated 1000 line input is hard to debug by staring at it, so
+//! we repeatedly throw parts of the input away for as long as the failure still
+//! reproduces. This happens at decreasing granularity: first whole blocks (the maps of
+//! day 5), then lines and finally tokens within each line.
+
+use crate::{
+    differential::{self, Outcome},
+    fuzz,
+    validate::{self, Severity},
+};
+
+/// What makes an input "failing"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    /// A solver and its oracle give different answers on an input without schema errors
+    Disagree,
+    /// One of the day's fuzz targets crashes
+    Panics,
+}
+
+impl std::str::FromStr for Predicate {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disagree" => Ok(Self::Disagree),
+            "panics" => Ok(Self::Panics),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Predicate {
+    pub fn holds(self, year: usize, day: usize, input: &str) -> bool {
+        match self {
+            Predicate::Disagree => {
+                // without these checks we'd happily shrink down to some broken input that
+                // makes both sides panic in different ways
+                let has_errors = validate::schema(year, day).is_none_or(|schema| {
+                    schema
+                        .validate(input)
+                        .iter()
+                        .any(|violation| violation.severity == Severity::Error)
+                });
+                !has_errors
+                    && differential::compare(year, day, input)
+                        .iter()
+                        .any(|mismatch| {
+                            matches!(
+                                (&mismatch.solver, &mismatch.oracle),
+                                (Outcome::Answer(_), Outcome::Answer(_))
+                            )
+                        })
+            }
+            Predicate::Panics => fuzz::TARGETS
+                .iter()
+                .filter(|target| target.year == year && target.day == day)
+                .any(|target| fuzz::crashes(target, input.as_bytes()).is_some()),
+        }
+    }
+}
+
+/// The classic ddmin algorithm: find a (1-minimal) subsequence of `items` that's still
+/// failing. `items` itself has to be failing.
+pub fn ddmin<T: Clone>(mut items: Vec<T>, mut failing: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut n = 2;
+    while items.len() >= 2 {
+        let chunk_len = items.len().div_ceil(n);
+        let chunks = (0..items.len()).step_by(chunk_len).collect::<Vec<_>>();
+        // first try to reduce to a single chunk ...
+        let subset = chunks.iter().find_map(|&start| {
+            let subset = items[start..(start + chunk_len).min(items.len())].to_vec();
+            failing(&subset).then_some(subset)
+        });
+        if let Some(subset) = subset {
+            items = subset;
+            n = 2;
+            continue;
+        }
+        // ... then to remove a single chunk
+        let complement = chunks.iter().find_map(|&start| {
+            let mut complement = items[..start].to_vec();
+            complement.extend_from_slice(&items[(start + chunk_len).min(items.len())..]);
+            failing(&complement).then_some(complement)
+        });
+        if let Some(complement) = complement {
+            items = complement;
+            n = (n - 1).max(2);
+            continue;
+        }
+        // neither worked: go finer
+        if n >= items.len() {
+            break;
+        }
+        n = (2 * n).min(items.len());
+    }
+    items
+}
+
+/// Split a line into runs of digits, runs of letters and single other characters
+pub fn tokens(line: &str) -> Vec<&str> {
+    let class = |c: char| {
+        if c.is_ascii_digit() {
+            0
+        } else if c.is_alphabetic() {
+            1
+        } else {
+            2
+        }
+    };
+    let mut tokens = vec![];
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        let end = idx + c.len_utf8();
+        let continues = chars
+            .peek()
+            .is_some_and(|&(_, next)| class(next) == class(c) && class(c) != 2);
+        if !continues {
+            tokens.push(&line[start..end]);
+            start = end;
+        }
+    }
+    tokens
+}
+
+/// Shrink a failing input
+pub fn minimize(input: &str, mut failing: impl FnMut(&str) -> bool) -> String {
+    assert!(failing(input), "the input has to fail to begin with");
+
+    let blocks = input.split("\n\n").map(str::to_string).collect();
+    let blocks = ddmin(blocks, |blocks| failing(&blocks.join("\n\n")));
+    let input = blocks.join("\n\n");
+
+    let lines = input.lines().map(str::to_string).collect();
+    let mut lines = ddmin(lines, |lines| failing(&lines.join("\n")));
+
+    for idx in 0..lines.len() {
+        let line_tokens = tokens(&lines[idx])
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let line = ddmin(line_tokens, |line_tokens| {
+            let mut candidate = lines.clone();
+            candidate[idx] = line_tokens.concat();
+            failing(&candidate.join("\n"))
+        })
+        .concat();
+        lines[idx] = line;
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,9 @@
+//! Brute-force reference implementations.
+//!
+//! These trade all cleverness for being obviously correct: they follow the puzzle text
+//! as literally as possible (expanding ranges point by point, simulating every card copy,
+//! looking at every cell around every number, ...). They're way too slow for the real
+//! inputs but fine for small generated ones, which makes them a good baseline for
+//! differential testing the actual solutions.
+
+pub mod y2023;
@@ -0,0 +1,49 @@
+const NUMERALS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn calibration_value(digits: &[usize]) -> usize {
+    10 * digits.first().unwrap() + digits.last().unwrap()
+}
+
+pub fn fst(input: &str) -> usize {
+    input
+        .lines()
+        .map(|line| {
+            let digits = line
+                .chars()
+                .filter_map(|c| c.to_digit(10))
+                .map(|d| d as usize)
+                .collect::<Vec<_>>();
+            // a line without digits counts as 0 in part 1
+            if digits.is_empty() {
+                0
+            } else {
+                calibration_value(&digits)
+            }
+        })
+        .sum()
+}
+
+pub fn snd(input: &str) -> usize {
+    input
+        .lines()
+        .map(|line| {
+            // try every position of the line as a start of a digit or numeral
+            let digits = (0..line.len())
+                .filter_map(|start| {
+                    let tail = &line[start..];
+                    let c = tail.chars().next().unwrap();
+                    if let Some(d) = c.to_digit(10) {
+                        return Some(d as usize);
+                    }
+                    (1..)
+                        .zip(NUMERALS)
+                        .find(|(_, num)| tail.starts_with(num))
+                        .map(|(d, _)| d)
+                })
+                .collect::<Vec<_>>();
+            calibration_value(&digits)
+        })
+        .sum()
+}
@@ -0,0 +1,47 @@
+/// The number of cubes of each color `[red, green, blue]` in each draw of a game
+fn parse_game(line: &str) -> (usize, Vec<[usize; 3]>) {
+    let (head, tail) = line.split_once(": ").unwrap();
+    let game_id = head.strip_prefix("Game ").unwrap().parse().unwrap();
+    let draws = tail
+        .split("; ")
+        .map(|draw| {
+            let mut counts = [0; 3];
+            for cubes in draw.split(", ") {
+                let (count, color) = cubes.split_once(' ').unwrap();
+                let idx = ["red", "green", "blue"]
+                    .iter()
+                    .position(|c| *c == color)
+                    .unwrap();
+                counts[idx] += count.parse::<usize>().unwrap();
+            }
+            counts
+        })
+        .collect();
+    (game_id, draws)
+}
+
+pub fn fst(input: &str) -> usize {
+    let bag = [12, 13, 14];
+    input
+        .lines()
+        .map(parse_game)
+        .filter(|(_, draws)| {
+            draws
+                .iter()
+                .all(|draw| (0..3).all(|color| draw[color] <= bag[color]))
+        })
+        .map(|(game_id, _)| game_id)
+        .sum()
+}
+
+pub fn snd(input: &str) -> usize {
+    input
+        .lines()
+        .map(parse_game)
+        .map(|(_, draws)| {
+            (0..3)
+                .map(|color| draws.iter().map(|draw| draw[color]).max().unwrap())
+                .product::<usize>()
+        })
+        .sum()
+}
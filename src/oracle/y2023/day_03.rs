@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+
+/// A number in the grid: its value, row and the columns it covers
+struct Number {
+    val: usize,
+    row: usize,
+    cols: std::ops::Range<usize>,
+}
+
+fn numbers(grid: &[Vec<u8>]) -> Vec<Number> {
+    let mut numbers = vec![];
+    for (row, line) in grid.iter().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            if line[col].is_ascii_digit() {
+                let start = col;
+                while col < line.len() && line[col].is_ascii_digit() {
+                    col += 1;
+                }
+                let val = std::str::from_utf8(&line[start..col])
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                numbers.push(Number {
+                    val,
+                    row,
+                    cols: start..col,
+                });
+            } else {
+                col += 1;
+            }
+        }
+    }
+    numbers
+}
+
+/// All cells touching a number, including diagonally
+fn neighbours(grid: &[Vec<u8>], num: &Number) -> Vec<(usize, usize)> {
+    let mut cells = vec![];
+    for row in num.row.saturating_sub(1)..=num.row + 1 {
+        for col in num.cols.start.saturating_sub(1)..=num.cols.end {
+            let inside = row == num.row && num.cols.contains(&col);
+            if !inside && row < grid.len() && col < grid[row].len() {
+                cells.push((row, col));
+            }
+        }
+    }
+    cells
+}
+
+fn is_symbol(c: u8) -> bool {
+    !c.is_ascii_digit() && c != b'.'
+}
+
+fn grid(input: &str) -> Vec<Vec<u8>> {
+    input.lines().map(|line| line.bytes().collect()).collect()
+}
+
+pub fn fst(input: &str) -> usize {
+    let grid = grid(input);
+    numbers(&grid)
+        .into_iter()
+        .filter(|num| {
+            neighbours(&grid, num)
+                .into_iter()
+                .any(|(row, col)| is_symbol(grid[row][col]))
+        })
+        .map(|num| num.val)
+        .sum()
+}
+
+pub fn snd(input: &str) -> usize {
+    let grid = grid(input);
+    let numbers = numbers(&grid);
+    let mut ratios = 0;
+    for (row, line) in grid.iter().enumerate() {
+        for (col, &c) in line.iter().enumerate() {
+            if c != b'*' {
+                continue;
+            }
+            let adjacent = numbers
+                .iter()
+                .enumerate()
+                .filter(|(_, num)| neighbours(&grid, num).contains(&(row, col)))
+                .map(|(idx, _)| idx)
+                .collect::<HashSet<_>>();
+            if adjacent.len() == 2 {
+                ratios += adjacent
+                    .iter()
+                    .map(|&idx| numbers[idx].val)
+                    .product::<usize>();
+            }
+        }
+    }
+    ratios
+}
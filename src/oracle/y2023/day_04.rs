@@ -0,0 +1,34 @@
+/// The number of our own numbers that are winning numbers, for each card
+fn wins(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':').unwrap();
+            let (winning, own) = numbers.split_once('|').unwrap();
+            let winning = winning.split_ascii_whitespace().collect::<Vec<_>>();
+            own.split_ascii_whitespace()
+                .filter(|num| winning.contains(num))
+                .count()
+        })
+        .collect()
+}
+
+pub fn fst(input: &str) -> usize {
+    wins(input)
+        .into_iter()
+        .map(|wins| if wins == 0 { 0 } else { 1 << (wins - 1) })
+        .sum()
+}
+
+pub fn snd(input: &str) -> usize {
+    let wins = wins(input);
+    // we start out with one of each card and scratch them one at a time, putting
+    // every card we win on the pile
+    let mut pile = (0..wins.len()).collect::<Vec<_>>();
+    let mut scratched = 0;
+    while let Some(card) = pile.pop() {
+        scratched += 1;
+        pile.extend((card + 1..=card + wins[card]).filter(|&won| won < wins.len()));
+    }
+    scratched
+}
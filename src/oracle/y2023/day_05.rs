@@ -0,0 +1,56 @@
+/// A map as a list of `(dest_start, source_start, len)`
+type Map = Vec<(usize, usize, usize)>;
+
+fn parse(input: &str) -> (Vec<usize>, Vec<Map>) {
+    let mut blocks = input.split("\n\n");
+    let seeds = blocks
+        .next()
+        .unwrap()
+        .split_ascii_whitespace()
+        .skip(1)
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let maps = blocks
+        .map(|block| {
+            block
+                .lines()
+                .skip(1)
+                .map(|line| {
+                    let nums = line
+                        .split_ascii_whitespace()
+                        .map(|s| s.parse().unwrap())
+                        .collect::<Vec<usize>>();
+                    (nums[0], nums[1], nums[2])
+                })
+                .collect()
+        })
+        .collect();
+    (seeds, maps)
+}
+
+fn location(maps: &[Map], seed: usize) -> usize {
+    maps.iter().fold(seed, |current, map| {
+        map.iter()
+            .find(|&&(_, source, len)| source <= current && current < source + len)
+            .map_or(current, |&(dest, source, _)| dest + (current - source))
+    })
+}
+
+pub fn fst(input: &str) -> usize {
+    let (seeds, maps) = parse(input);
+    seeds
+        .into_iter()
+        .map(|seed| location(&maps, seed))
+        .min()
+        .unwrap()
+}
+
+pub fn snd(input: &str) -> usize {
+    let (seeds, maps) = parse(input);
+    seeds
+        .chunks_exact(2)
+        .flat_map(|pair| pair[0]..pair[0] + pair[1])
+        .map(|seed| location(&maps, seed))
+        .min()
+        .unwrap()
+}
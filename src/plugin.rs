@@ -0,0 +1,287 @@
+//! Solutions loaded at runtime from shared libraries, so that experimental versions of a
+//! day can be tried without rebuilding this crate.
+//!
+//! Every library in the plugins directory (see the [config](crate::config)) exports two
+//! functions with the C ABI: `aoc_plugin_abi_version`, returning the [`ABI_VERSION`] it
+//! was built against, and `aoc_plugin_declaration`, returning its [`Declaration`].
+//! Libraries built against another version are rejected before anything else of them
+//! is looked at. A plugin written in Rust is a `cdylib` that declares its solvers with
+//! [`export_plugin!`](crate::export_plugin), like `examples/plugin.rs`:
+//!
+//! ```sh
+//! cargo build --example plugin
+//! cp target/debug/examples/libplugin.so plugins/
+//! ```
+//!
+//! The runner lists the loaded plugins with `aoc plugins` and checks their solvers as
+//! extra variants of the days, just like ours.
+
+use std::{
+    ffi::{c_char, CStr},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    slice,
+    sync::Arc,
+};
+
+use libloading::Library;
+
+use crate::{
+    days::Solver,
+    differential::{self, Outcome},
+};
+
+/// Version of the plugin ABI, bumped whenever anything about it changes
+pub const ABI_VERSION: u32 = 1;
+
+const VERSION_SYMBOL: &[u8] = b"aoc_plugin_abi_version\0";
+const DECLARATION_SYMBOL: &[u8] = b"aoc_plugin_declaration\0";
+
+/// Solve a part on an input of `len` bytes of UTF-8, writing the answer to `answer`.
+/// Returns 0 if it did and anything else if it failed.
+pub type SolveFn = unsafe extern "C" fn(input: *const u8, len: usize, answer: *mut u64) -> i32;
+
+/// A plugin's solver for one part of a day
+#[repr(C)]
+pub struct SolverDecl {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub solve: SolveFn,
+}
+
+/// What a plugin provides: its name as a NUL-terminated UTF-8 string and its solvers.
+/// Both have to stay valid for as long as the library is loaded.
+#[repr(C)]
+pub struct Declaration {
+    pub name: *const c_char,
+    pub solvers: *const SolverDecl,
+    pub len: usize,
+}
+
+// plugins only ever point to their statics
+unsafe impl Sync for Declaration {}
+
+/// Call one of our solvers the way a [`SolveFn`] is called, for
+/// [`export_plugin!`](crate::export_plugin): invalid UTF-8 and panics are reported as
+/// failures instead of unwinding into the caller
+///
+/// # Safety
+///
+/// `input` has to point to `len` readable bytes (unless `len` is 0) and `answer` to a
+/// writable `u64`.
+pub unsafe fn call(solver: Solver, input: *const u8, len: usize, answer: *mut u64) -> i32 {
+    let bytes = if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(input, len)
+    };
+    let Ok(input) = std::str::from_utf8(bytes) else {
+        return 1;
+    };
+    match differential::run(solver, input) {
+        Outcome::Answer(solution) => {
+            *answer = solution as u64;
+            0
+        }
+        Outcome::Panic(_) => 2,
+    }
+}
+
+/// Export the plugin functions for some of our solvers from a `cdylib`, given the
+/// plugin's name and `(year, day, part, solver)` tuples
+#[macro_export]
+macro_rules! export_plugin {
+    ($name:literal, [$(($year:literal, $day:literal, $part:literal, $solver:path)),* $(,)?]) => {
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_abi_version() -> u32 {
+            $crate::plugin::ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_declaration() -> *const $crate::plugin::Declaration {
+            static SOLVERS: &[$crate::plugin::SolverDecl] = &[$(
+                $crate::plugin::SolverDecl {
+                    year: $year,
+                    day: $day,
+                    part: $part,
+                    solve: {
+                        unsafe extern "C" fn solve(
+                            input: *const u8,
+                            len: usize,
+                            answer: *mut u64,
+                        ) -> i32 {
+                            unsafe { $crate::plugin::call($solver, input, len, answer) }
+                        }
+                        solve
+                    },
+                }
+            ),*];
+            static DECLARATION: $crate::plugin::Declaration = $crate::plugin::Declaration {
+                name: concat!($name, "\0").as_ptr().cast(),
+                solvers: SOLVERS.as_ptr(),
+                len: SOLVERS.len(),
+            };
+            &DECLARATION
+        }
+    };
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Load(libloading::Error),
+    /// The library doesn't export the plugin functions
+    NotAPlugin,
+    /// The library was built against another ABI version
+    Version(u32),
+    InvalidName,
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Load(err) => write!(f, "{err}"),
+            Error::NotAPlugin => write!(f, "not a plugin (the plugin functions are missing)"),
+            Error::Version(version) => write!(
+                f,
+                "built against plugin ABI version {version}, but this is version {ABI_VERSION}"
+            ),
+            Error::InvalidName => write!(f, "the plugin's name isn't valid UTF-8"),
+            Error::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// A plugin's solver for one part of a day
+#[derive(Debug, Clone)]
+pub struct Variant {
+    /// Name of the plugin it's from
+    pub plugin: String,
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    solve: SolveFn,
+    // keeps the library loaded for as long as any of its variants are around
+    _library: Arc<Library>,
+}
+
+impl Variant {
+    pub fn run(&self, input: &str) -> Outcome {
+        let mut answer = 0;
+        // SAFETY: the input is valid for its length and the answer is writable, which is
+        // all the ABI asks for
+        match unsafe { (self.solve)(input.as_ptr(), input.len(), &mut answer) } {
+            0 => Outcome::Answer(answer as usize),
+            status => Outcome::Panic(format!("plugin {} failed ({status})", self.plugin)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Plugin {
+    pub name: String,
+    pub path: PathBuf,
+    pub variants: Vec<Variant>,
+}
+
+impl Plugin {
+    /// Load a plugin from a shared library
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialization code, and the plugin functions have to
+    /// stick to the ABI - this trusts the library completely.
+    pub unsafe fn load(path: &Path) -> Result<Self, Error> {
+        let library = Arc::new(Library::new(path).map_err(Error::Load)?);
+        let version = library
+            .get::<extern "C" fn() -> u32>(VERSION_SYMBOL)
+            .map_err(|_| Error::NotAPlugin)?();
+        if version != ABI_VERSION {
+            return Err(Error::Version(version));
+        }
+        let declaration = library
+            .get::<extern "C" fn() -> *const Declaration>(DECLARATION_SYMBOL)
+            .map_err(|_| Error::NotAPlugin)?();
+        let Some(declaration) = declaration.as_ref() else {
+            return Err(Error::NotAPlugin);
+        };
+        if declaration.name.is_null() {
+            return Err(Error::InvalidName);
+        }
+        let name = CStr::from_ptr(declaration.name)
+            .to_str()
+            .map_err(|_| Error::InvalidName)?
+            .to_string();
+        let solvers = if declaration.len == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(declaration.solvers, declaration.len)
+        };
+        let variants = solvers
+            .iter()
+            .map(|solver| Variant {
+                plugin: name.clone(),
+                year: solver.year as usize,
+                day: solver.day as usize,
+                part: solver.part as usize,
+                solve: solver.solve,
+                _library: library.clone(),
+            })
+            .collect();
+        Ok(Self {
+            name,
+            path: path.to_path_buf(),
+            variants,
+        })
+    }
+}
+
+/// All the plugins in a directory, and the libraries in it that couldn't be loaded
+#[derive(Debug, Default)]
+pub struct Plugins {
+    pub plugins: Vec<Plugin>,
+    pub errors: Vec<(PathBuf, Error)>,
+}
+
+impl Plugins {
+    /// Load every shared library in a directory (there are no plugins if it doesn't
+    /// exist)
+    ///
+    /// # Safety
+    ///
+    /// See [`Plugin::load`].
+    pub unsafe fn load(dir: &Path) -> Self {
+        let mut plugins = Self::default();
+        let mut paths = match fs::read_dir(dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION)
+                })
+                .collect::<Vec<_>>(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => {
+                plugins.errors.push((dir.to_path_buf(), Error::Io(err)));
+                vec![]
+            }
+        };
+        paths.sort();
+        for path in paths {
+            match Plugin::load(&path) {
+                Ok(plugin) => plugins.plugins.push(plugin),
+                Err(err) => plugins.errors.push((path, err)),
+            }
+        }
+        plugins
+    }
+
+    /// The variants of all plugins for some day
+    pub fn variants(&self, year: usize, day: usize) -> impl Iterator<Item = &Variant> {
+        self.plugins
+            .iter()
+            .flat_map(|plugin| &plugin.variants)
+            .filter(move |variant| variant.year == year && variant.day == day)
+    }
+}
@@ -0,0 +1,36 @@
+//! Answers computed while compiling, by running the `const fn` versions of some
+//! solutions over the embedded inputs of the profile the build was for. They're `None`
+//! for days whose input wasn't embedded.
+//!
+//! Looping over whole inputs takes the compiler long enough for its
+//! `long_running_const_eval` lint, which is allowed here. The answers are `static`s rather
+//! than `const`s so that they're evaluated once, in this crate, and not again (hitting the
+//! lint) in every crate using them.
+
+#![allow(long_running_const_eval)]
+
+/// Evaluate a pair of `const fn` solvers on an embedded input
+macro_rules! precompute {
+    ($year:literal, $day:literal, $module:ident) => {
+        pub mod $module {
+            use crate::y2023::$module;
+
+            const INPUT: Option<&str> = crate::input::embedded_plain($year, $day);
+
+            pub static FST: Option<usize> = match INPUT {
+                Some(input) => Some($module::fst_const(input)),
+                None => None,
+            };
+
+            pub static SND: Option<usize> = match INPUT {
+                Some(input) => Some($module::snd_const(input)),
+                None => None,
+            };
+        }
+    };
+}
+
+pub mod y2023 {
+    precompute!(2023, 1, day_01);
+    precompute!(2023, 4, day_04);
+}
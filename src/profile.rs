@@ -0,0 +1,96 @@
+//! Profiles for sharing one checkout between multiple people.
+//!
+//! Everyone gets different puzzle inputs, so inputs, answers and the submission history
+//! are all stored per profile and year in `inputs/<profile>/<year>/`. The current profile
+//! is taken from the `AOC_PROFILE` environment variable and defaults to `default`.
+
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::input;
+
+pub const PROFILE_ENV: &str = "AOC_PROFILE";
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Profile(String);
+
+impl Profile {
+    /// Panics on an invalid name, see [`Profile::from_str`] for the fallible version
+    pub fn new(name: &str) -> Self {
+        name.parse()
+            .unwrap_or_else(|_| panic!("invalid profile name {name:?}"))
+    }
+
+    /// The profile selected via the environment
+    pub fn current() -> Self {
+        match std::env::var(PROFILE_ENV) {
+            Ok(name) => Self::new(&name),
+            Err(_) => Self::new(DEFAULT_PROFILE),
+        }
+    }
+
+    /// All profiles that have stored inputs
+    pub fn all() -> Vec<Self> {
+        let mut profiles = Self::in_dir(&input::dir());
+        #[cfg(feature = "embed")]
+        profiles.extend(input::embedded_profiles().map(Self::new));
+        profiles.sort();
+        profiles.dedup();
+        profiles
+    }
+
+    /// The profiles with a directory in `dir`, sorted. Directories that aren't valid
+    /// profile names (like `.cache`) aren't profiles and are skipped.
+    pub fn in_dir(dir: &Path) -> Vec<Self> {
+        let mut profiles = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|ty| ty.is_dir()))
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter_map(|name| name.parse().ok())
+            .collect::<Vec<_>>();
+        profiles.sort();
+        profiles
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// The directory holding everything belonging to this profile
+    pub fn dir(&self) -> PathBuf {
+        input::dir().join(&self.0)
+    }
+
+    /// The directory holding everything belonging to this profile for one year
+    pub fn year_dir(&self, year: usize) -> PathBuf {
+        self.dir().join(year.to_string())
+    }
+}
+
+/// Names are non-empty and made of ascii letters, digits, `-` and `_`, so that they're
+/// safe to use as directory names
+impl FromStr for Profile {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty()
+            || !s
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(());
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,187 @@
+//! When we started, first submitted and solved each part of a [`Profile`].
+//!
+//! Kept in `progress.txt` in the profile's directory for the year with one
+//! `DAY PART STARTED FIRST_SUBMISSION STAR` line per part, all of them seconds since the
+//! unix epoch or `-` if it hasn't happened (yet). Anything missing is filled in from the
+//! submission history when loading, so profiles from before this file existed still get
+//! their stars - just not their start times.
+
+use std::{collections::BTreeMap, fmt::Write as _, fs, io, path::PathBuf, time::Duration};
+
+use crate::{
+    answers::{self, Submission, Verdict},
+    check,
+    profile::Profile,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Entry {
+    pub started: Option<u64>,
+    pub first_submission: Option<u64>,
+    pub star: Option<u64>,
+}
+
+impl Entry {
+    /// How long it took from starting to getting the star
+    pub fn solve_time(&self) -> Option<u64> {
+        Some(self.star?.saturating_sub(self.started?))
+    }
+}
+
+/// The progress on every part we've touched, keyed by `(day, part)`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress(pub BTreeMap<(usize, usize), Entry>);
+
+const PROGRESS_FILE: &str = "progress.txt";
+
+fn progress_path(profile: &Profile, year: usize) -> PathBuf {
+    profile.year_dir(year).join(PROGRESS_FILE)
+}
+
+/// `None` if the field isn't a time or `-`
+fn parse_time(field: &str) -> Option<Option<u64>> {
+    match field {
+        "-" => Some(None),
+        _ => field.parse().ok().map(Some),
+    }
+}
+
+fn format_time(time: Option<u64>) -> String {
+    time.map_or("-".to_string(), |time| time.to_string())
+}
+
+impl Progress {
+    pub fn load(profile: &Profile, year: usize) -> Self {
+        let mut progress = fs::read_to_string(progress_path(profile, year))
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default();
+        for submission in answers::submissions(profile, year) {
+            progress.note(&submission);
+        }
+        progress
+    }
+
+    /// Parse the contents of `progress.txt` (without the submissions), skipping
+    /// malformed lines
+    pub fn parse(content: &str) -> Self {
+        Self(
+            answers::entries(content, PROGRESS_FILE, |line| {
+                let [day, part, started, first_submission, star] = answers::fields(line)?;
+                Some((
+                    (day.parse().ok()?, part.parse().ok()?),
+                    Entry {
+                        started: parse_time(started)?,
+                        first_submission: parse_time(first_submission)?,
+                        star: parse_time(star)?,
+                    },
+                ))
+            })
+            .collect(),
+        )
+    }
+
+    pub fn save(&self, profile: &Profile, year: usize) -> io::Result<()> {
+        fs::create_dir_all(profile.year_dir(year))?;
+        fs::write(
+            progress_path(profile, year),
+            self.0
+                .iter()
+                .map(|((day, part), entry)| {
+                    format!(
+                        "{day} {part} {} {} {}\n",
+                        format_time(entry.started),
+                        format_time(entry.first_submission),
+                        format_time(entry.star)
+                    )
+                })
+                .collect::<String>(),
+        )
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Entry {
+        self.0.get(&(day, part)).copied().unwrap_or_default()
+    }
+
+    /// Mark a part as started, unless it already was
+    pub fn start(&mut self, day: usize, part: usize, time: u64) {
+        self.0
+            .entry((day, part))
+            .or_default()
+            .started
+            .get_or_insert(time);
+    }
+
+    /// Take a submission into account, in any order. Part 2 only unlocks once part 1 is
+    /// solved, so that's when it counts as started.
+    pub fn note(&mut self, submission: &Submission) {
+        let entry = self.0.entry((submission.day, submission.part)).or_default();
+        let first = entry.first_submission.get_or_insert(submission.time);
+        *first = (*first).min(submission.time);
+        if submission.verdict == Verdict::Correct {
+            let star = entry.star.get_or_insert(submission.time);
+            *star = (*star).min(submission.time);
+            if submission.part == 1 {
+                let part_2 = self.0.entry((submission.day, 2)).or_default();
+                let started = part_2.started.get_or_insert(submission.time);
+                *started = (*started).min(submission.time);
+            }
+        }
+    }
+
+    /// The time between getting the first and the second star of a day
+    pub fn delta(&self, day: usize) -> Option<u64> {
+        Some(
+            self.get(day, 2)
+                .star?
+                .saturating_sub(self.get(day, 1).star?),
+        )
+    }
+
+    /// An advent calendar of the stars followed by our solve times for every day we
+    /// got a star on
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let stars = self.0.values().filter(|entry| entry.star.is_some()).count();
+        let _ = writeln!(out, "{stars}/50 stars\n");
+        let border = "+------".repeat(5) + "+";
+        for week in 0..5 {
+            let _ = writeln!(out, "{border}");
+            for day in week * 5 + 1..=week * 5 + 5 {
+                let star = |part| match self.get(day, part).star {
+                    Some(_) => '*',
+                    None => '.',
+                };
+                let _ = write!(out, "| {day:>2} {}{}", star(1), star(2));
+            }
+            out.push_str("|\n");
+        }
+        let _ = writeln!(out, "{border}\n");
+
+        let _ = writeln!(
+            out,
+            "{:>3}  {:>10}  {:>10}  {:>10}",
+            "day", "part 1", "part 2", "delta"
+        );
+        for day in 1..=25 {
+            if self.get(day, 1).star.is_none() {
+                continue;
+            }
+            let _ = writeln!(
+                out,
+                "{day:>3}  {:>10}  {:>10}  {:>10}",
+                format_seconds(self.get(day, 1).solve_time()),
+                format_seconds(self.get(day, 2).solve_time()),
+                format_seconds(self.delta(day)),
+            );
+        }
+        out
+    }
+}
+
+/// Format a number of seconds [like any other duration](check::format_duration), `-`
+/// if there's none
+pub fn format_seconds(seconds: Option<u64>) -> String {
+    seconds.map_or("-".to_string(), |s| {
+        check::format_duration(Duration::from_secs(s))
+    })
+}
@@ -0,0 +1,124 @@
+//! Converts the puzzle descriptions to Markdown.
+//!
+//! Only covers the handful of elements the descriptions are written in; anything else is
+//! reduced to its text.
+
+use super::html::{Element, Node};
+
+/// Collapse runs of whitespace the way a browser would when rendering inline text
+fn collapse_whitespace(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut space = false;
+    for c in s.chars() {
+        if c.is_whitespace() && c != '\u{a0}' {
+            space = true;
+        } else {
+            if space {
+                out.push(' ');
+                space = false;
+            }
+            out.push(c);
+        }
+    }
+    if space {
+        out.push(' ');
+    }
+    out
+}
+
+fn inline(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => collapse_whitespace(text),
+            Node::Element(element) => inline_element(element),
+        })
+        .collect()
+}
+
+fn inline_element(element: &Element) -> String {
+    match element.name.as_str() {
+        "code" => {
+            let code = format!("`{}`", element.text());
+            // highlighted code can't be expressed inside of a code span, so the
+            // highlight goes around it
+            if element.find_all("em").is_empty() {
+                code
+            } else {
+                format!("**{code}**")
+            }
+        }
+        "em" if !element.find_all("code").is_empty() => {
+            format!("**{}**", inline(&element.children))
+        }
+        "em" => format!("*{}*", inline(&element.children).trim()),
+        "a" => match element.attr("href") {
+            Some(href) => format!("[{}]({href})", inline(&element.children)),
+            None => inline(&element.children),
+        },
+        "br" => "  \n".to_string(),
+        _ => inline(&element.children),
+    }
+}
+
+fn block(element: &Element, out: &mut String) {
+    match element.name.as_str() {
+        "h2" => {
+            let title = element.text();
+            let title = title.trim().trim_matches('-').trim();
+            out.push_str(&format!("## {title}\n\n"));
+        }
+        "p" => out.push_str(&format!("{}\n\n", inline(&element.children).trim())),
+        "pre" => {
+            let code = element.text();
+            let code = code.trim_end_matches('\n');
+            out.push_str(&format!("```\n{code}\n```\n\n"));
+        }
+        "ul" | "ol" => {
+            for (i, item) in element.find_all("li").into_iter().enumerate() {
+                let marker = match element.name.as_str() {
+                    "ol" => format!("{}.", i + 1),
+                    _ => "-".to_string(),
+                };
+                out.push_str(&format!("{marker} {}\n", inline(&item.children).trim()));
+            }
+            out.push('\n');
+        }
+        _ => {
+            // a container: treat any loose inline content as a paragraph of its own
+            let mut pending = vec![];
+            for child in &element.children {
+                match child {
+                    Node::Element(child) if is_block(&child.name) => {
+                        flush(&mut pending, out);
+                        block(child, out);
+                    }
+                    _ => pending.push(child.clone()),
+                }
+            }
+            flush(&mut pending, out);
+        }
+    }
+}
+
+fn is_block(name: &str) -> bool {
+    matches!(
+        name,
+        "article" | "div" | "h2" | "main" | "ol" | "p" | "pre" | "section" | "ul"
+    )
+}
+
+fn flush(pending: &mut Vec<Node>, out: &mut String) {
+    let text = inline(pending);
+    if !text.trim().is_empty() {
+        out.push_str(&format!("{}\n\n", text.trim()));
+    }
+    pending.clear();
+}
+
+/// Convert an element (usually an `<article>`) to Markdown
+pub fn to_markdown(element: &Element) -> String {
+    let mut out = String::new();
+    block(element, &mut out);
+    out
+}
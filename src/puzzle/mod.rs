@@ -0,0 +1,194 @@
+//! Saved puzzle pages: their descriptions for reading offline and the examples in them.
+//!
+//! A page saved from the site is converted to Markdown and cached as
+//! `puzzles/<year>/day_XX/description.md` (which isn't committed - the texts aren't ours to
+//! share). The examples are guessed from it: every part's description shows its example
+//! input in a `<pre><code>` block and highlights the answer for it as the last
+//! `<code><em>` of the part. Part 2 often reuses the example of part 1.
+//!
+//! Examples we accept are stored in `puzzles/<year>/day_XX/examples.txt` as blocks of
+//!
+//! ```text
+//! == part 1: 142 ==
+//! 1abc2
+//! pqr3stu8vwx
+//! ```
+//!
+//! and checked against the solutions by the tests.
+
+use std::{fmt, fs, io, path::PathBuf, str::FromStr};
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::config::Config;
+
+pub mod html;
+pub mod markdown;
+
+/// The directory the cached descriptions and the examples are stored in
+pub fn dir(year: usize, day: usize) -> PathBuf {
+    Config::get()
+        .puzzles_dir()
+        .join(year.to_string())
+        .join(format!("day_{day:02}"))
+}
+
+/// A saved puzzle page
+pub struct Page {
+    /// The description of each part that's unlocked
+    pub articles: Vec<html::Element>,
+}
+
+impl Page {
+    pub fn parse(html: &str) -> Self {
+        let document = html::parse(html);
+        Self {
+            articles: document.find_all("article").into_iter().cloned().collect(),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        self.articles
+            .iter()
+            .map(markdown::to_markdown)
+            .join("")
+            .trim_end()
+            .to_string()
+            + "\n"
+    }
+
+    /// Save the description as Markdown
+    pub fn cache(&self, year: usize, day: usize) -> io::Result<PathBuf> {
+        let path = dir(year, day).join("description.md");
+        fs::create_dir_all(dir(year, day))?;
+        fs::write(&path, self.to_markdown())?;
+        Ok(path)
+    }
+
+    /// Our best guesses for the example of each part and its answer
+    pub fn examples(&self) -> Vec<Example> {
+        let mut previous: Option<String> = None;
+        let mut examples = vec![];
+        for (part, article) in (1..).zip(&self.articles) {
+            let blocks = code_blocks(article);
+            let input = blocks.last().cloned().or(previous.clone());
+            let answer = emphasized(article)
+                .into_iter()
+                .rfind(|em| em.parse::<usize>().is_ok());
+            if let (Some(input), Some(answer)) = (input, answer) {
+                examples.push(Example {
+                    part,
+                    input,
+                    answer: answer.parse().unwrap(),
+                });
+            }
+            if let Some(block) = blocks.last() {
+                previous = Some(block.clone());
+            }
+        }
+        examples
+    }
+}
+
+/// The contents of all `<pre><code>` blocks, with the trailing newline dropped
+pub fn code_blocks(element: &html::Element) -> Vec<String> {
+    element
+        .find_all("pre")
+        .into_iter()
+        .flat_map(|pre| pre.find_all("code"))
+        .map(|code| code.text().trim_end_matches('\n').to_string())
+        .collect()
+}
+
+/// The text of every highlighted bit of code - `<code><em>` as well as `<em><code>`
+pub fn emphasized(element: &html::Element) -> Vec<String> {
+    element
+        .find_all("code")
+        .into_iter()
+        .flat_map(|code| {
+            let inner = code.find_all("em");
+            let outer = element
+                .find_all("em")
+                .into_iter()
+                .any(|em| em.find_all("code").contains(&code));
+            (outer || !inner.is_empty()).then(|| code.text())
+        })
+        .collect()
+}
+
+/// An example input with the answer for a part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: usize,
+    pub input: String,
+    pub answer: usize,
+}
+
+impl fmt::Display for Example {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "== part {}: {} ==", self.part, self.answer)?;
+        writeln!(f, "{}", self.input)
+    }
+}
+
+static HEADER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^== part (\d+): (\d+) ==$").unwrap());
+
+/// A list of examples in the format of `examples.txt`
+pub struct Examples(pub Vec<Example>);
+
+impl FromStr for Examples {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut examples: Vec<Example> = vec![];
+        for line in s.lines() {
+            if let Some(caps) = HEADER.captures(line) {
+                examples.push(Example {
+                    part: caps[1].parse().unwrap(),
+                    answer: caps[2].parse().unwrap(),
+                    input: String::new(),
+                });
+            } else {
+                let example = examples.last_mut().ok_or(())?;
+                example.input.push_str(line);
+                example.input.push('\n');
+            }
+        }
+        for example in &mut examples {
+            example
+                .input
+                .truncate(example.input.trim_end_matches('\n').len());
+        }
+        Ok(Self(examples))
+    }
+}
+
+impl Examples {
+    fn path(year: usize, day: usize) -> PathBuf {
+        dir(year, day).join("examples.txt")
+    }
+
+    pub fn load(year: usize, day: usize) -> Self {
+        match fs::read_to_string(Self::path(year, day)) {
+            Ok(content) => content
+                .parse()
+                .unwrap_or_else(|()| panic!("malformed examples for {year} day {day}")),
+            Err(_) => Self(vec![]),
+        }
+    }
+
+    /// Add an example unless we already have it, returning whether it was new
+    pub fn add(&mut self, example: Example) -> bool {
+        let new = !self.0.contains(&example);
+        if new {
+            self.0.push(example);
+        }
+        new
+    }
+
+    pub fn save(&self, year: usize, day: usize) -> io::Result<()> {
+        fs::create_dir_all(dir(year, day))?;
+        fs::write(Self::path(year, day), self.0.iter().join("\n"))
+    }
+}
@@ -0,0 +1,254 @@
+//! Python bindings for the parsers and solvers, behind the `python` feature.
+//!
+//! Like the rest of the tooling everything is keyed by year and day:
+//!
+//! ```python
+//! import aoc
+//! aoc.days()                      # [(2023, 1), (2023, 2), ...]
+//! aoc.parse(2023, 2, text)        # [{"id": 1, "draws": [{"blue": 3, "red": 4}, ...]}, ...]
+//! aoc.solve(2023, 2, 1, text)     # 8
+//! aoc.examples(2023, 4)           # [{"part": 1, "input": "...", "answer": 13}, ...]
+//! ```
+//!
+//! Inputs are checked against the day's [schema](crate::validate) first and rejected
+//! with a `ValueError` listing the violations, rather than letting the parsers panic.
+//! Whatever gets past that and still can't be parsed or makes a solver panic is a
+//! `ValueError` too.
+//! Build the module with `maturin develop` (see `pyproject.toml`), or by hand:
+//!
+//! ```sh
+//! cargo build --release --features extension-module
+//! cp target/release/libaoc.so python/aoc.so
+//! PYTHONPATH=python python -m unittest discover -s python/tests
+//! ```
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyList},
+};
+
+use crate::{
+    days,
+    differential::{self, Outcome},
+    puzzle::Examples,
+    validate::{self, Severity},
+    y2023::{day_01, day_02, day_03, day_04, day_05},
+};
+
+/// Raise a `ValueError` if an input breaks the schema of its day
+fn check(year: usize, day: usize, input: &str) -> PyResult<()> {
+    let errors = validate::schema(year, day)
+        .map(|schema| schema.validate(input))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|violation| violation.severity == Severity::Error)
+        .map(|violation| violation.to_string())
+        .collect_vec();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "invalid input for {year} day {day}:\n{}",
+            errors.join("\n")
+        )))
+    }
+}
+
+/// The implemented days as `(year, day)` pairs
+#[pyfunction(name = "days")]
+fn implemented() -> Vec<(usize, usize)> {
+    days::DAYS.iter().map(|day| (day.year, day.day)).collect()
+}
+
+/// Solve a part (1 or 2) of a day
+#[pyfunction]
+fn solve(year: usize, day: usize, part: usize, input: &str) -> PyResult<usize> {
+    let solver = days::get(year, day)
+        .and_then(|solutions| *solutions.solvers.get(part.wrapping_sub(1))?)
+        .ok_or_else(|| {
+            PyValueError::new_err(format!("{year} day {day} part {part} isn't implemented"))
+        })?;
+    check(year, day, input)?;
+    match differential::run(solver, input) {
+        Outcome::Answer(answer) => Ok(answer),
+        Outcome::Panic(msg) => Err(PyValueError::new_err(format!(
+            "{year} day {day} part {part} panicked: {msg}"
+        ))),
+    }
+}
+
+/// Parse with a day's parser, raising a `ValueError` if it fails
+fn parsed<T: FromStr>(s: &str) -> PyResult<T> {
+    s.parse()
+        .map_err(|_| PyValueError::new_err(format!("can't parse {s:?}")))
+}
+
+/// Parse an input into the day's structures, as dicts and lists
+#[pyfunction]
+fn parse<'py>(
+    py: Python<'py>,
+    year: usize,
+    day: usize,
+    input: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    check(year, day, input)?;
+    match (year, day) {
+        (2023, 1) => Ok(input
+            .lines()
+            .map(|line| day_01::parse_digits(line).collect_vec())
+            .collect_vec()
+            .into_pyobject(py)?
+            .into_any()),
+        (2023, 2) => list(py, input.lines().map(|line| game(py, line))),
+        (2023, 3) => schematic(py, input),
+        (2023, 4) => list(py, input.lines().map(|line| card(py, line))),
+        (2023, 5) => almanac(py, input),
+        _ => Err(PyValueError::new_err(format!(
+            "{year} day {day} has no parser"
+        ))),
+    }
+}
+
+/// The examples from the puzzle description of a day, as dicts with the part, input and
+/// answer
+#[pyfunction]
+fn examples(py: Python<'_>, year: usize, day: usize) -> PyResult<Bound<'_, PyAny>> {
+    list(
+        py,
+        Examples::load(year, day).0.into_iter().map(|example| {
+            let dict = PyDict::new(py);
+            dict.set_item("part", example.part)?;
+            dict.set_item("input", example.input)?;
+            dict.set_item("answer", example.answer)?;
+            Ok(dict)
+        }),
+    )
+}
+
+fn list<'py>(
+    py: Python<'py>,
+    items: impl Iterator<Item = PyResult<Bound<'py, PyDict>>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    Ok(PyList::new(py, items.collect::<PyResult<Vec<_>>>()?)?.into_any())
+}
+
+fn game<'py>(py: Python<'py>, line: &str) -> PyResult<Bound<'py, PyDict>> {
+    let game = parsed::<day_02::Game>(line)?;
+    let draws = game
+        .draws
+        .iter()
+        .map(|draw| {
+            let dict = PyDict::new(py);
+            for (color, count) in draw {
+                let color = match color {
+                    day_02::Color::Red => "red",
+                    day_02::Color::Green => "green",
+                    day_02::Color::Blue => "blue",
+                };
+                dict.set_item(color, count)?;
+            }
+            Ok(dict)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let dict = PyDict::new(py);
+    dict.set_item("id", game.game_id)?;
+    dict.set_item("draws", draws)?;
+    Ok(dict)
+}
+
+fn symbol<'py>(py: Python<'py>, symbol: &day_03::Symbol) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("symbol", symbol.val)?;
+    dict.set_item("line", symbol.line_idx)?;
+    dict.set_item("idx", symbol.idx)?;
+    Ok(dict)
+}
+
+fn number<'py>(py: Python<'py>, number: &day_03::Number) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("value", number.val)?;
+    dict.set_item("line", number.line_idx)?;
+    dict.set_item("start", number.span.start())?;
+    dict.set_item("end", number.span.end())?;
+    Ok(dict)
+}
+
+/// The symbols and numbers of a schematic, and which numbers are next to each symbol
+fn schematic<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyAny>> {
+    use day_03::SchematicEntry as S;
+
+    let schematic = parsed::<day_03::Schematic>(input)?;
+    let entries = schematic.lines.iter().flat_map(|line| &line.0);
+    let symbols = entries.clone().filter_map(|entry| match entry {
+        S::Symbol(sym) => Some(symbol(py, sym)),
+        S::Number(_) => None,
+    });
+    let numbers = entries.filter_map(|entry| match entry {
+        S::Number(num) => Some(number(py, num)),
+        S::Symbol(_) => None,
+    });
+    let adjacencies = schematic
+        .adjacencies()
+        .into_iter()
+        .sorted_by_key(|(sym, _)| (sym.line_idx, sym.idx))
+        .map(|(sym, nums)| {
+            let nums = nums
+                .into_iter()
+                .sorted_by_key(|num| (num.line_idx, *num.span.start()))
+                .map(|num| number(py, num));
+            let dict = PyDict::new(py);
+            dict.set_item("symbol", symbol(py, sym)?)?;
+            dict.set_item("numbers", list(py, nums)?)?;
+            Ok(dict)
+        });
+    let dict = PyDict::new(py);
+    dict.set_item("symbols", list(py, symbols)?)?;
+    dict.set_item("numbers", list(py, numbers)?)?;
+    dict.set_item("adjacencies", list(py, adjacencies)?)?;
+    Ok(dict.into_any())
+}
+
+fn card<'py>(py: Python<'py>, line: &str) -> PyResult<Bound<'py, PyDict>> {
+    let card = parsed::<day_04::Card>(line)?;
+    let dict = PyDict::new(py);
+    dict.set_item("id", card.id)?;
+    dict.set_item("winning", &card.winning_nums)?;
+    dict.set_item("mine", &card.my_nums)?;
+    dict.set_item("wins", card.count_wins())?;
+    Ok(dict)
+}
+
+/// The seeds and the maps, each with its name and ranges
+fn almanac<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyAny>> {
+    let almanac = parsed::<day_05::Almanac>(input)?;
+    let maps = almanac.maps.iter().map(|(name, map)| {
+        let ranges = map.0.iter().map(|range| {
+            let dict = PyDict::new(py);
+            dict.set_item("dest_start", range.dest_start)?;
+            dict.set_item("source_start", range.source_start)?;
+            dict.set_item("len", range.len)?;
+            Ok(dict)
+        });
+        let dict = PyDict::new(py);
+        dict.set_item("name", name)?;
+        dict.set_item("ranges", list(py, ranges)?)?;
+        Ok(dict)
+    });
+    let dict = PyDict::new(py);
+    dict.set_item("seeds", &almanac.seeds)?;
+    dict.set_item("maps", list(py, maps)?)?;
+    Ok(dict.into_any())
+}
+
+#[pymodule]
+fn aoc(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(implemented, module)?)?;
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    module.add_function(wrap_pyfunction!(parse, module)?)?;
+    module.add_function(wrap_pyfunction!(examples, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,390 @@
+//! A small command language for poking at the parsed puzzle inputs.
+//!
+//! Meant for debugging: load a day's input and ask things like "which numbers touch the
+//! symbol at line 12, column 40" or "where does seed 79 end up" instead of sprinkling
+//! `println!`s over the solutions. Positions are 0-based, ids and line numbers are the
+//! ones from the puzzle.
+
+use std::{collections::HashMap, str::FromStr};
+
+use itertools::Itertools;
+
+use crate::{
+    days,
+    input::Source,
+    profile::Profile,
+    validate::{self, Severity},
+    y2023::{
+        day_01,
+        day_02::{Color, Game},
+        day_03::{try_get_gear, Schematic, SchematicEntry},
+        day_04::{points_from_count, Card},
+        day_05::Almanac,
+    },
+};
+
+const HELP: &str = "\
+general:
+  load DAY [PATH]        load the input of a day (the stored one without a path)
+  solve [PART]           run the solutions on the loaded input
+  help                   show this message
+  quit                   leave
+day 1:
+  line N                 the digits both parts find in line N
+day 2:
+  game N                 the draws of game N and the smallest bag for them
+  games COLOR OP N       games where the most cubes of a color shown at once compare
+                         to N, e.g. `games blue > 15` (OP is one of < <= = >= >)
+day 3:
+  symbol LINE COL        the numbers touching the symbol at a position
+  number LINE COL        the number covering a position and the symbols it touches
+  gears                  all gears with their ratios
+day 4:
+  card N                 card N with its wins and points
+  copies N               how many copies of card N we end up with
+day 5:
+  seeds                  the seeds with their locations
+  trace SEED             follow a seed through all the maps
+  map N|NAME             the ranges of a map";
+
+/// A parsed input
+enum Loaded {
+    /// A day there are no commands for (yet), which can still be solved
+    Other,
+    Day01(Vec<String>),
+    Day02(Vec<Game>),
+    Day03(Schematic),
+    Day04(Vec<Card>),
+    Day05(Almanac),
+}
+
+pub struct Session {
+    year: usize,
+    loaded: Option<(usize, String, Loaded)>,
+}
+
+type Reply = Result<String, String>;
+
+fn parse_arg<T: FromStr>(arg: Option<&&str>, what: &str) -> Result<T, String> {
+    let arg = arg.ok_or_else(|| format!("missing {what}"))?;
+    arg.parse().map_err(|_| format!("invalid {what} {arg:?}"))
+}
+
+impl Session {
+    pub fn new(year: usize) -> Self {
+        Self { year, loaded: None }
+    }
+
+    /// Execute a single command, returning what to show the user
+    pub fn execute(&mut self, line: &str) -> Reply {
+        let words = line.split_ascii_whitespace().collect::<Vec<_>>();
+        let Some((&cmd, args)) = words.split_first() else {
+            return Ok(String::new());
+        };
+        match cmd {
+            "help" => Ok(HELP.to_string()),
+            "load" => self.load(args),
+            "solve" => self.solve(args),
+            _ => match &self.loaded {
+                None => Err("nothing loaded yet, try `load DAY`".to_string()),
+                Some((_, _, loaded)) => match (loaded, cmd) {
+                    (Loaded::Day01(lines), "line") => line_digits(lines, args),
+                    (Loaded::Day02(games), "game") => game(games, args),
+                    (Loaded::Day02(games), "games") => games_where(games, args),
+                    (Loaded::Day03(schematic), "symbol") => symbol(schematic, args),
+                    (Loaded::Day03(schematic), "number") => number(schematic, args),
+                    (Loaded::Day03(schematic), "gears") => gears(schematic),
+                    (Loaded::Day04(cards), "card") => card(cards, args),
+                    (Loaded::Day04(cards), "copies") => copies(cards, args),
+                    (Loaded::Day05(almanac), "seeds") => seeds(almanac),
+                    (Loaded::Day05(almanac), "trace") => trace(almanac, args),
+                    (Loaded::Day05(almanac), "map") => map(almanac, args),
+                    _ => Err(format!("unknown command {cmd:?} (see `help`)")),
+                },
+            },
+        }
+    }
+
+    fn load(&mut self, args: &[&str]) -> Reply {
+        let year = self.year;
+        let day: usize = parse_arg(args.first(), "day")?;
+        if days::get(year, day).is_none() {
+            return Err(format!("{year} day {day} isn't implemented"));
+        }
+        let source = match args.get(1) {
+            // the commands are coming from stdin
+            Some(&"-") => return Err("can't load an input from stdin, use a file".to_string()),
+            Some(arg) => Source::from_arg(arg),
+            None => Source::Stored(Profile::current()),
+        };
+        let input = source.read(year, day).map_err(|err| err.to_string())?;
+        if let Some(schema) = validate::schema(year, day) {
+            let errors = schema
+                .validate(&input)
+                .into_iter()
+                .filter(|violation| violation.severity == Severity::Error)
+                .join("\n");
+            if !errors.is_empty() {
+                return Err(format!("the input doesn't look like day {day}:\n{errors}"));
+            }
+        }
+        let loaded = match (year, day) {
+            (2023, 1) => Ok(Loaded::Day01(input.lines().map(str::to_string).collect())),
+            (2023, 2) => input
+                .lines()
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map(Loaded::Day02),
+            (2023, 3) => input.parse().map(Loaded::Day03),
+            (2023, 4) => input
+                .lines()
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map(Loaded::Day04),
+            (2023, 5) => input.parse().map(Loaded::Day05),
+            _ => Ok(Loaded::Other),
+        }
+        .map_err(|()| format!("can't parse the input as {year} day {day}"))?;
+        let lines = input.lines().count();
+        self.loaded = Some((day, input, loaded));
+        Ok(format!("loaded day {day} ({lines} lines)"))
+    }
+
+    fn solve(&self, args: &[&str]) -> Reply {
+        let Some((day, input, _)) = &self.loaded else {
+            return Err("nothing loaded yet, try `load DAY`".to_string());
+        };
+        let parts = match args.first() {
+            Some(_) => vec![parse_arg::<usize>(args.first(), "part")?],
+            None => vec![1, 2],
+        };
+        let solutions = days::get(self.year, *day).unwrap();
+        parts
+            .into_iter()
+            .map(|part| {
+                let solver = solutions
+                    .solvers
+                    .get(part.wrapping_sub(1))
+                    .ok_or_else(|| format!("there's no part {part}"))?;
+                Ok(match solver {
+                    Some(solver) => format!("part {part}: {}", solver(input)),
+                    None => format!("part {part}: not implemented"),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|lines| lines.join("\n"))
+    }
+}
+
+fn line_digits(lines: &[String], args: &[&str]) -> Reply {
+    let n: usize = parse_arg(args.first(), "line number")?;
+    let line = lines
+        .get(n.wrapping_sub(1))
+        .ok_or_else(|| format!("there's no line {n}"))?;
+    let ascii = line
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect::<String>();
+    let digits = day_01::parse_digits(line).join("");
+    Ok(format!(
+        "{line}\npart 1 digits: {ascii}\npart 2 digits: {digits}"
+    ))
+}
+
+fn game(games: &[Game], args: &[&str]) -> Reply {
+    let id: usize = parse_arg(args.first(), "game id")?;
+    let game = games
+        .iter()
+        .find(|game| game.game_id == id)
+        .ok_or_else(|| format!("there's no game {id}"))?;
+    let draws = game
+        .draws
+        .iter()
+        .map(|draw| {
+            draw.iter()
+                .sorted_by_key(|(color, _)| format!("{color:?}"))
+                .map(|(color, count)| format!("{count} {color:?}"))
+                .join(", ")
+        })
+        .join("; ");
+    let bag = game.minimal_possible_bag();
+    Ok(format!(
+        "game {id}: {draws}\nsmallest bag: {} red, {} green, {} blue (power {})",
+        bag.0[&Color::Red],
+        bag.0[&Color::Green],
+        bag.0[&Color::Blue],
+        bag.power()
+    ))
+}
+
+fn games_where(games: &[Game], args: &[&str]) -> Reply {
+    let color: Color = parse_arg(args.first(), "color")?;
+    let op = *args.get(1).ok_or("missing comparison")?;
+    let n: usize = parse_arg(args.get(2), "count")?;
+    let compare: fn(&usize, &usize) -> bool = match op {
+        "<" => usize::lt,
+        "<=" => usize::le,
+        "=" | "==" => usize::eq,
+        ">=" => usize::ge,
+        ">" => usize::gt,
+        _ => return Err(format!("unknown comparison {op:?}")),
+    };
+    let ids = games
+        .iter()
+        .filter(|game| compare(&game.minimal_possible_bag().0[&color], &n))
+        .map(|game| game.game_id)
+        .collect_vec();
+    Ok(format!("{} games: {}", ids.len(), ids.iter().join(", ")))
+}
+
+fn position(args: &[&str]) -> Result<(usize, usize), String> {
+    Ok((
+        parse_arg(args.first(), "line")?,
+        parse_arg(args.get(1), "column")?,
+    ))
+}
+
+fn symbol(schematic: &Schematic, args: &[&str]) -> Reply {
+    let (line_idx, idx) = position(args)?;
+    let adjacencies = schematic.adjacencies();
+    let (sym, nums) = adjacencies
+        .iter()
+        .find(|(sym, _)| sym.line_idx == line_idx && sym.idx == idx)
+        .ok_or_else(|| format!("no symbol touching a number at ({line_idx}, {idx})"))?;
+    let nums = nums
+        .iter()
+        .sorted_by_key(|num| (num.line_idx, *num.span.start()))
+        .map(|num| format!("{} at ({}, {:?})", num.val, num.line_idx, num.span))
+        .join("\n  ");
+    Ok(format!("{:?} touches\n  {nums}", sym.val))
+}
+
+fn number(schematic: &Schematic, args: &[&str]) -> Reply {
+    let (line_idx, idx) = position(args)?;
+    let num = schematic
+        .lines
+        .get(line_idx)
+        .into_iter()
+        .flat_map(|line| &line.0)
+        .find_map(|entry| match entry {
+            SchematicEntry::Number(num) if num.span.contains(&idx) => Some(num),
+            _ => None,
+        })
+        .ok_or_else(|| format!("no number at ({line_idx}, {idx})"))?;
+    let symbols = schematic
+        .adjacencies()
+        .into_iter()
+        .filter(|(_, nums)| nums.contains(num))
+        .map(|(sym, _)| sym)
+        .sorted_by_key(|sym| (sym.line_idx, sym.idx))
+        .map(|sym| format!("{:?} at ({}, {})", sym.val, sym.line_idx, sym.idx))
+        .collect_vec();
+    if symbols.is_empty() {
+        Ok(format!("{} isn't a part number", num.val))
+    } else {
+        Ok(format!("{} touches\n  {}", num.val, symbols.join("\n  ")))
+    }
+}
+
+fn gears(schematic: &Schematic) -> Reply {
+    let gears = schematic
+        .adjacencies()
+        .iter()
+        .filter_map(|(sym, nums)| try_get_gear(sym, nums))
+        .sorted_by_key(|(sym, _)| (sym.line_idx, sym.idx))
+        .map(|(sym, [l, r])| {
+            format!(
+                "({}, {}): {} * {} = {}",
+                sym.line_idx,
+                sym.idx,
+                l.val,
+                r.val,
+                l.val * r.val
+            )
+        })
+        .collect_vec();
+    Ok(format!("{} gears\n{}", gears.len(), gears.join("\n")))
+}
+
+fn card(cards: &[Card], args: &[&str]) -> Reply {
+    let id: usize = parse_arg(args.first(), "card id")?;
+    let card = cards
+        .iter()
+        .find(|card| card.id == id)
+        .ok_or_else(|| format!("there's no card {id}"))?;
+    let wins = card.count_wins();
+    Ok(format!(
+        "card {id}: {} | {}\n{wins} wins, {} points",
+        card.winning_nums.iter().join(" "),
+        card.my_nums.iter().join(" "),
+        points_from_count(wins)
+    ))
+}
+
+fn copies(cards: &[Card], args: &[&str]) -> Reply {
+    let id: usize = parse_arg(args.first(), "card id")?;
+    let pos = cards
+        .iter()
+        .position(|card| card.id == id)
+        .ok_or_else(|| format!("there's no card {id}"))?;
+    let mut copies = vec![1; cards.len()];
+    let mut won_by = HashMap::<usize, Vec<usize>>::new();
+    for (i, card) in cards.iter().enumerate().take(pos) {
+        for j in (i + 1..=i + card.count_wins()).filter(|&j| j < cards.len()) {
+            copies[j] += copies[i];
+            won_by.entry(j).or_default().push(card.id);
+        }
+    }
+    let sources = won_by.get(&pos).map_or("no other card".to_string(), |ids| {
+        format!("cards {}", ids.iter().join(", "))
+    });
+    Ok(format!(
+        "{} copies of card {id}, won from {sources}",
+        copies[pos]
+    ))
+}
+
+fn seeds(almanac: &Almanac) -> Reply {
+    Ok(almanac
+        .seeds
+        .iter()
+        .map(|&seed| format!("{seed} -> {}", almanac.location(seed)))
+        .join("\n"))
+}
+
+fn trace(almanac: &Almanac, args: &[&str]) -> Reply {
+    let seed: usize = parse_arg(args.first(), "seed")?;
+    Ok(std::iter::once(format!("seed {seed}"))
+        .chain(
+            almanac
+                .maps
+                .iter()
+                .zip(almanac.trace(seed))
+                .map(|((name, _), value)| format!("{name}: {value}")),
+        )
+        .join("\n"))
+}
+
+fn map(almanac: &Almanac, args: &[&str]) -> Reply {
+    let key = *args.first().ok_or("missing map")?;
+    let (name, map) = match key.parse::<usize>() {
+        Ok(n) => almanac.maps.get(n.wrapping_sub(1)),
+        Err(_) => almanac.maps.iter().find(|(name, _)| name == key),
+    }
+    .ok_or_else(|| format!("there's no map {key:?}"))?;
+    let ranges = map
+        .0
+        .iter()
+        .sorted_by_key(|range| range.source_start)
+        .map(|range| {
+            format!(
+                "{}..{} -> {}..{}",
+                range.source_start,
+                range.source_end(),
+                range.dest_start,
+                range.dest_end()
+            )
+        })
+        .join("\n");
+    Ok(format!("{name}\n{ranges}"))
+}
@@ -0,0 +1,239 @@
+//! Snapshot tests of intermediate results.
+//!
+//! A value is rendered to text by [`Snapshot`] and compared with the one stored in
+//! `tests/snapshots/NAME.snap`. The rendering is stable: unordered collections are
+//! sorted by their rendered items, and anything nested goes on its own indented lines so
+//! that a change shows up as a readable diff.
+//!
+//! Run the tests with `UPDATE_SNAPSHOTS=1` to accept the current values instead, which
+//! also creates the files of new snapshots:
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test --test snapshots
+//! ```
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    env, fs,
+    path::PathBuf,
+};
+
+use crate::y2023::{day_03, day_05};
+
+/// Environment variable that makes [`assert_snapshot`] store values instead of comparing
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Values with a stable text rendering
+pub trait Snapshot {
+    fn snapshot(&self) -> String;
+}
+
+/// Indent all lines of some text by two spaces
+fn indent(text: &str) -> String {
+    text.lines().map(|line| format!("  {line}\n")).collect()
+}
+
+/// Render items between brackets, one per line
+fn block(open: &str, items: impl IntoIterator<Item = String>, close: &str) -> String {
+    let items = items
+        .into_iter()
+        .map(|item| indent(&item))
+        .collect::<String>();
+    if items.is_empty() {
+        format!("{open}{close}")
+    } else {
+        format!("{open}\n{items}{close}")
+    }
+}
+
+fn sorted(items: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut items = items.into_iter().collect::<Vec<_>>();
+    items.sort();
+    items
+}
+
+macro_rules! display_snapshot {
+    ($($t:ty),*) => {
+        $(impl Snapshot for $t {
+            fn snapshot(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+display_snapshot!(usize, u64, u32, i64, i32, bool);
+
+impl Snapshot for char {
+    fn snapshot(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl Snapshot for str {
+    fn snapshot(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl Snapshot for String {
+    fn snapshot(&self) -> String {
+        self.as_str().snapshot()
+    }
+}
+
+impl<T: Snapshot + ?Sized> Snapshot for &T {
+    fn snapshot(&self) -> String {
+        (**self).snapshot()
+    }
+}
+
+impl<T: Snapshot> Snapshot for Option<T> {
+    fn snapshot(&self) -> String {
+        match self {
+            Some(value) => value.snapshot(),
+            None => "none".to_string(),
+        }
+    }
+}
+
+impl<A: Snapshot, B: Snapshot> Snapshot for (A, B) {
+    fn snapshot(&self) -> String {
+        format!("{} => {}", self.0.snapshot(), self.1.snapshot())
+    }
+}
+
+impl<T: Snapshot> Snapshot for [T] {
+    fn snapshot(&self) -> String {
+        block("[", self.iter().map(Snapshot::snapshot), "]")
+    }
+}
+
+impl<T: Snapshot> Snapshot for Vec<T> {
+    fn snapshot(&self) -> String {
+        self.as_slice().snapshot()
+    }
+}
+
+impl<T: Snapshot, S> Snapshot for HashSet<T, S> {
+    fn snapshot(&self) -> String {
+        block("{", sorted(self.iter().map(Snapshot::snapshot)), "}")
+    }
+}
+
+impl<T: Snapshot> Snapshot for BTreeSet<T> {
+    fn snapshot(&self) -> String {
+        block("{", sorted(self.iter().map(Snapshot::snapshot)), "}")
+    }
+}
+
+impl<K: Snapshot, V: Snapshot, S> Snapshot for HashMap<K, V, S> {
+    fn snapshot(&self) -> String {
+        block("{", sorted(self.iter().map(|entry| entry.snapshot())), "}")
+    }
+}
+
+impl<K: Snapshot, V: Snapshot> Snapshot for BTreeMap<K, V> {
+    fn snapshot(&self) -> String {
+        block("{", sorted(self.iter().map(|entry| entry.snapshot())), "}")
+    }
+}
+
+impl Snapshot for day_03::Symbol {
+    fn snapshot(&self) -> String {
+        format!("{:?} at {}:{}", self.val, self.line_idx, self.idx)
+    }
+}
+
+impl Snapshot for day_03::Number {
+    fn snapshot(&self) -> String {
+        format!(
+            "{} at {}:{}..={}",
+            self.val,
+            self.line_idx,
+            self.span.start(),
+            self.span.end()
+        )
+    }
+}
+
+impl Snapshot for day_05::Range {
+    fn snapshot(&self) -> String {
+        format!(
+            "{}..{} -> {}..{}",
+            self.source_start,
+            self.source_end(),
+            self.dest_start,
+            self.dest_end()
+        )
+    }
+}
+
+impl Snapshot for day_05::Map {
+    fn snapshot(&self) -> String {
+        self.0.snapshot()
+    }
+}
+
+/// Where the snapshot with some name is stored
+pub fn path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.snap"))
+}
+
+/// Compare a value against its stored snapshot, panicking with a diff if they differ -
+/// or store it in update mode
+pub fn assert_snapshot(name: &str, value: &(impl Snapshot + ?Sized)) {
+    let actual = value.snapshot() + "\n";
+    let path = path(name);
+    if env::var_os(UPDATE_ENV).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap_or_else(|err| panic!("can't write {name}: {err}"));
+        return;
+    }
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!("no snapshot {name} yet, rerun with {UPDATE_ENV}=1 to create it");
+    };
+    if actual != expected {
+        panic!(
+            "snapshot {name} changed (rerun with {UPDATE_ENV}=1 to accept it):\n{}",
+            diff(&expected, &actual)
+        );
+    }
+}
+
+/// A line diff of two texts, with removed lines marked `-` and added ones `+`
+pub fn diff(old: &str, new: &str) -> String {
+    let (old, new) = (
+        old.lines().collect::<Vec<_>>(),
+        new.lines().collect::<Vec<_>>(),
+    );
+    // lengths of the longest common subsequences of all suffixes
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            out += &format!(" {}\n", old[i]);
+            (i, j) = (i + 1, j + 1);
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out += &format!("-{}\n", old[i]);
+            i += 1;
+        } else {
+            out += &format!("+{}\n", new[j]);
+            j += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,141 @@
+//! Structural checks on puzzle inputs.
+//!
+//! The solutions rely on a few properties of their input that the puzzle text only
+//! implies - card ids being consecutive, map ranges not overlapping and so on. Each day
+//! declares these properties as a [`Schema`] that's checked before solving starts, so
+//! that a broken assumption shows up as a readable report rather than a wrong answer
+//! or an `unwrap` somewhere deep inside the solution.
+
+use std::fmt;
+
+pub mod y2023;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The solution will still run but might produce a wrong answer (or only one of the
+    /// parts might work)
+    Warning,
+    /// The solution can't handle this input
+    Error,
+}
+
+/// A single failed check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub check: &'static str,
+    pub severity: Severity,
+    /// 1-based line number in the input if the violation can be pinned to a line
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{severity}[{}]", self.check)?;
+        if let Some(line) = self.line {
+            write!(f, " line {line}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// What a check reports: an optional (0-based) line index together with a message
+pub type Finding = (Option<usize>, String);
+
+/// A single named assumption about the input
+pub struct Check {
+    pub name: &'static str,
+    pub severity: Severity,
+    pub run: fn(&str) -> Vec<Finding>,
+}
+
+/// All the assumptions a day's solution makes about its input
+pub struct Schema {
+    pub year: usize,
+    pub day: usize,
+    pub checks: &'static [Check],
+}
+
+impl Schema {
+    /// Run all checks against the input and collect the violations
+    pub fn validate(&self, input: &str) -> Vec<Violation> {
+        self.checks
+            .iter()
+            .flat_map(|check| {
+                (check.run)(input)
+                    .into_iter()
+                    .map(move |(line_idx, message)| Violation {
+                        check: check.name,
+                        severity: check.severity,
+                        line: line_idx.map(|idx| idx + 1),
+                        message,
+                    })
+            })
+            .collect()
+    }
+
+    /// Validate the input, print all violations to stderr and panic if any of them are errors.
+    ///
+    /// This is the hook the solutions call before they start solving.
+    pub fn enforce(&self, input: &str) {
+        let violations = self.validate(input);
+        for violation in &violations {
+            eprintln!("day {}: {violation}", self.day);
+        }
+        let errors = violations
+            .iter()
+            .filter(|v| v.severity == Severity::Error)
+            .count();
+        if errors != 0 {
+            panic!(
+                "{} day {}: input violates {errors} of the solution's assumptions",
+                self.year, self.day
+            );
+        }
+    }
+}
+
+/// Get the schema for some day
+pub fn schema(year: usize, day: usize) -> Option<&'static Schema> {
+    match (year, day) {
+        (2023, 1) => Some(&y2023::day_01::SCHEMA),
+        (2023, 2) => Some(&y2023::day_02::SCHEMA),
+        (2023, 3) => Some(&y2023::day_03::SCHEMA),
+        (2023, 4) => Some(&y2023::day_04::SCHEMA),
+        (2023, 5) => Some(&y2023::day_05::SCHEMA),
+        _ => None,
+    }
+}
+
+/// Check that the input isn't empty and doesn't contain blank lines (shared by the days
+/// that have one record per line)
+pub(crate) fn no_blank_lines(input: &str) -> Vec<Finding> {
+    if input.trim().is_empty() {
+        return vec![(None, "input is empty".to_string())];
+    }
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.trim().is_empty())
+        .map(|(idx, _)| (Some(idx), "blank line".to_string()))
+        .collect()
+}
+
+/// Check that all numbers in the input are at most `max` (they get parsed into fixed size
+/// integers all over the place)
+pub(crate) fn numbers_fit(input: &str, max: usize) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(idx, line)| {
+            line.split(|c: char| !c.is_ascii_digit())
+                .filter(|digits| !digits.is_empty())
+                .filter(move |digits| digits.parse::<usize>().map_or(true, |n| n > max))
+                .map(move |digits| (Some(idx), format!("{digits} is larger than {max}")))
+        })
+        .collect()
+}
@@ -0,0 +1,78 @@
+use crate::validate::{no_blank_lines, Check, Finding, Schema, Severity};
+
+const NUMERALS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub static SCHEMA: Schema = Schema {
+    year: 2023,
+    day: 1,
+    checks: &[
+        Check {
+            name: "no-blank-lines",
+            severity: Severity::Error,
+            run: no_blank_lines,
+        },
+        Check {
+            name: "has-digit",
+            severity: Severity::Warning,
+            run: has_digit,
+        },
+        Check {
+            name: "has-digit-or-numeral",
+            severity: Severity::Error,
+            run: has_digit_or_numeral,
+        },
+        Check {
+            name: "no-zero-numeral",
+            severity: Severity::Warning,
+            run: no_zero_numeral,
+        },
+    ],
+};
+
+/// Part 1 only looks at ascii digits and counts lines without any as 0, which is only
+/// right if they're meant for part 2
+fn has_digit(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter(|(_, line)| !line.chars().any(|c| c.is_ascii_digit()))
+        .map(|(idx, _)| {
+            (
+                Some(idx),
+                "no ascii digit, part 1 counts this line as 0".to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Part 2 also accepts spelled out numerals - but it still needs at least one of either
+fn has_digit_or_numeral(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter(|(_, line)| {
+            !line.chars().any(|c| c.is_ascii_digit())
+                && !NUMERALS.into_iter().any(|num| line.contains(num))
+        })
+        .map(|(idx, _)| (Some(idx), "neither a digit nor a numeral".to_string()))
+        .collect()
+}
+
+/// The solution parses `zero` as a numeral even though the puzzle doesn't count it
+fn no_zero_numeral(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains("zero"))
+        .map(|(idx, _)| {
+            (
+                Some(idx),
+                "contains `zero` which part 2 would count as a digit".to_string(),
+            )
+        })
+        .collect()
+}
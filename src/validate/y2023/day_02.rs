@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    validate::{no_blank_lines, numbers_fit, Check, Finding, Schema, Severity},
+    y2023::day_02::Game,
+};
+
+pub static SCHEMA: Schema = Schema {
+    year: 2023,
+    day: 2,
+    checks: &[
+        Check {
+            name: "no-blank-lines",
+            severity: Severity::Error,
+            run: no_blank_lines,
+        },
+        Check {
+            name: "game-syntax",
+            severity: Severity::Error,
+            run: game_syntax,
+        },
+        Check {
+            name: "unique-colors-per-draw",
+            severity: Severity::Warning,
+            run: unique_colors_per_draw,
+        },
+        Check {
+            name: "numbers-fit",
+            severity: Severity::Error,
+            run: |input| numbers_fit(input, usize::MAX),
+        },
+        Check {
+            name: "powers-fit",
+            severity: Severity::Error,
+            run: powers_fit,
+        },
+    ],
+};
+
+static GAME_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^Game \d+: \d+ (red|green|blue)((, |; )\d+ (red|green|blue))*$").unwrap()
+});
+
+fn game_syntax(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter(|(_, line)| !GAME_RE.is_match(line))
+        .map(|(idx, line)| (Some(idx), format!("not a valid game: {line:?}")))
+        .collect()
+}
+
+/// Draws are collected into maps keyed by color so a repeated color would silently
+/// overwrite the earlier count
+fn unique_colors_per_draw(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, line.split_once(": ")?.1)))
+        .flat_map(|(idx, draws)| {
+            draws.split("; ").filter_map(move |draw| {
+                let mut seen = HashSet::new();
+                draw.split(", ")
+                    .filter_map(|cubes| cubes.split_once(' '))
+                    .find(|(_, color)| !seen.insert(*color))
+                    .map(|(_, color)| (Some(idx), format!("{color} appears twice in {draw:?}")))
+            })
+        })
+        .collect()
+}
+
+/// Part 2 multiplies the fewest cubes of each color and adds those powers up
+fn powers_fit(input: &str) -> Vec<Finding> {
+    let mut total = 0usize;
+    for (idx, line) in input.lines().enumerate() {
+        let Ok(game) = line.parse::<Game>() else {
+            continue;
+        };
+        let power = game
+            .minimal_possible_bag()
+            .0
+            .values()
+            .try_fold(1usize, |power, &count| power.checked_mul(count));
+        match power.and_then(|power| total.checked_add(power)) {
+            Some(sum) => total = sum,
+            None => return vec![(Some(idx), "the sum of the powers overflows".to_string())],
+        }
+    }
+    vec![]
+}
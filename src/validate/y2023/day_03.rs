@@ -0,0 +1,74 @@
+use crate::validate::{numbers_fit, Check, Finding, Schema, Severity};
+
+pub static SCHEMA: Schema = Schema {
+    year: 2023,
+    day: 3,
+    checks: &[
+        Check {
+            name: "ascii",
+            severity: Severity::Error,
+            run: ascii,
+        },
+        Check {
+            name: "rectangular",
+            severity: Severity::Warning,
+            run: rectangular,
+        },
+        Check {
+            name: "no-whitespace",
+            severity: Severity::Warning,
+            run: no_whitespace,
+        },
+        Check {
+            name: "numbers-fit",
+            severity: Severity::Error,
+            run: |input| numbers_fit(input, u32::MAX as usize),
+        },
+    ],
+};
+
+/// Schematics are ascii: the other checks measure lines in bytes and the solution
+/// positions in chars
+fn ascii(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_ascii())
+        .map(|(idx, _)| (Some(idx), "contains non-ascii characters".to_string()))
+        .collect()
+}
+
+fn rectangular(input: &str) -> Vec<Finding> {
+    let Some(width) = input.lines().next().map(str::len) else {
+        return vec![(None, "input is empty".to_string())];
+    };
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.len() != width)
+        .map(|(idx, line)| {
+            (
+                Some(idx),
+                format!(
+                    "line is {} wide but the first line is {width} wide",
+                    line.len()
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Anything that's neither a digit nor a dot counts as a symbol - including whitespace
+fn no_whitespace(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.chars().any(char::is_whitespace))
+        .map(|(idx, _)| {
+            (
+                Some(idx),
+                "contains whitespace which would be treated as a symbol".to_string(),
+            )
+        })
+        .collect()
+}
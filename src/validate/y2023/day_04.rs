@@ -0,0 +1,155 @@
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    validate::{no_blank_lines, numbers_fit, Check, Finding, Schema, Severity},
+    y2023::day_04::Card,
+};
+
+pub static SCHEMA: Schema = Schema {
+    year: 2023,
+    day: 4,
+    checks: &[
+        Check {
+            name: "no-blank-lines",
+            severity: Severity::Error,
+            run: no_blank_lines,
+        },
+        Check {
+            name: "card-syntax",
+            severity: Severity::Error,
+            run: card_syntax,
+        },
+        Check {
+            name: "consecutive-ids",
+            severity: Severity::Error,
+            run: consecutive_ids,
+        },
+        Check {
+            name: "unique-numbers",
+            severity: Severity::Warning,
+            run: unique_numbers,
+        },
+        Check {
+            name: "numbers-fit",
+            severity: Severity::Error,
+            run: |input| numbers_fit(input, usize::MAX),
+        },
+        Check {
+            name: "points-fit",
+            severity: Severity::Error,
+            run: points_fit,
+        },
+        Check {
+            name: "copies-fit",
+            severity: Severity::Error,
+            run: copies_fit,
+        },
+    ],
+};
+
+static CARD_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^Card +(?P<id>\d+):(?P<win>( +\d+)+) \|(?P<mine>( +\d+)+)$").unwrap()
+});
+
+fn card_syntax(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter(|(_, line)| !CARD_RE.is_match(line))
+        .map(|(idx, line)| (Some(idx), format!("not a valid card: {line:?}")))
+        .collect()
+}
+
+/// The copy cascade of part 2 identifies cards by their position rather than their id,
+/// so the ids have to be 1, 2, 3, ...
+fn consecutive_ids(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, CARD_RE.captures(line)?["id"].parse::<usize>().ok()?)))
+        .zip(1..)
+        .filter(|((_, id), expected)| id != expected)
+        .map(|((idx, id), expected)| {
+            (
+                Some(idx),
+                format!("expected card {expected} but found card {id}"),
+            )
+        })
+        .collect()
+}
+
+/// Wins are counted over all pairs of numbers so duplicates get counted more than once
+fn unique_numbers(input: &str) -> Vec<Finding> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, CARD_RE.captures(line)?)))
+        .flat_map(|(idx, cap)| {
+            [("winning", &cap["win"]), ("own", &cap["mine"])]
+                .into_iter()
+                .filter_map(|(side, nums)| {
+                    nums.split_ascii_whitespace()
+                        .duplicates()
+                        .next()
+                        .map(|dup| {
+                            (
+                                Some(idx),
+                                format!("{side} number {dup} appears more than once"),
+                            )
+                        })
+                })
+                .collect_vec()
+        })
+        .collect()
+}
+
+/// The wins of the cards that parse, with their line indices
+fn wins(input: &str) -> Vec<(usize, usize)> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, line.parse::<Card>().ok()?.count_wins())))
+        .collect()
+}
+
+/// A card's points double with every win, so they overflow quickly
+fn points_fit(input: &str) -> Vec<Finding> {
+    let mut total = 0usize;
+    for (idx, wins) in wins(input) {
+        let points = match wins {
+            0 => Some(0),
+            n => u32::try_from(n - 1)
+                .ok()
+                .and_then(|exp| 2usize.checked_pow(exp)),
+        };
+        match points.and_then(|points| total.checked_add(points)) {
+            Some(sum) => total = sum,
+            None => return vec![(Some(idx), "the sum of the points overflows".to_string())],
+        }
+    }
+    vec![]
+}
+
+/// The copies won in part 2 can grow exponentially along the cards
+fn copies_fit(input: &str) -> Vec<Finding> {
+    let wins = wins(input);
+    let mut copies = vec![1usize; wins.len()];
+    let mut total = 0usize;
+    for (i, &(idx, wins)) in wins.iter().enumerate() {
+        let overflow = vec![(Some(idx), "the number of copies overflows".to_string())];
+        let Some(sum) = total.checked_add(copies[i]) else {
+            return overflow;
+        };
+        total = sum;
+        for j in i + 1..copies.len().min(i + 1 + wins) {
+            let Some(won) = copies[j].checked_add(copies[i]) else {
+                return overflow;
+            };
+            copies[j] = won;
+        }
+    }
+    vec![]
+}
@@ -0,0 +1,278 @@
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::validate::{numbers_fit, Check, Finding, Schema, Severity};
+
+pub static SCHEMA: Schema = Schema {
+    year: 2023,
+    day: 5,
+    checks: &[
+        Check {
+            name: "seeds",
+            severity: Severity::Error,
+            run: seeds,
+        },
+        Check {
+            name: "seed-pairs",
+            severity: Severity::Warning,
+            run: seed_pairs,
+        },
+        Check {
+            name: "seed-ranges",
+            severity: Severity::Error,
+            run: seed_ranges,
+        },
+        Check {
+            name: "map-syntax",
+            severity: Severity::Error,
+            run: map_syntax,
+        },
+        Check {
+            name: "category-chain",
+            severity: Severity::Error,
+            run: category_chain,
+        },
+        Check {
+            name: "non-overlapping",
+            severity: Severity::Error,
+            run: non_overlapping,
+        },
+        Check {
+            name: "ranges-fit",
+            severity: Severity::Error,
+            run: ranges_fit,
+        },
+        Check {
+            name: "numbers-fit",
+            severity: Severity::Error,
+            run: |input| numbers_fit(input, usize::MAX),
+        },
+    ],
+};
+
+static SEEDS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^seeds:( \d+)+$").unwrap());
+static HEADER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?P<source>[a-z]+)-to-(?P<dest>[a-z]+) map:$").unwrap());
+static RANGE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?P<dest_start>\d+) (?P<source_start>\d+) (?P<len>\d+)$").unwrap());
+
+/// A map block of the almanac: the line index of its header, the header itself and
+/// the (line index, line) pairs of its ranges
+struct Block<'a> {
+    header_idx: usize,
+    header: &'a str,
+    ranges: Vec<(usize, &'a str)>,
+}
+
+/// Split the almanac into its map blocks (skipping the seeds)
+fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks: Vec<Block> = vec![];
+    let mut in_block = false;
+    for (idx, line) in input.lines().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            in_block = false;
+        } else if in_block {
+            blocks.last_mut().unwrap().ranges.push((idx, line));
+        } else {
+            in_block = true;
+            blocks.push(Block {
+                header_idx: idx,
+                header: line,
+                ranges: vec![],
+            });
+        }
+    }
+    blocks
+}
+
+fn seeds(input: &str) -> Vec<Finding> {
+    match input.lines().next() {
+        Some(line) if SEEDS_RE.is_match(line) => vec![],
+        Some(line) => vec![(
+            Some(0),
+            format!("expected a list of seeds but found {line:?}"),
+        )],
+        None => vec![(None, "input is empty".to_string())],
+    }
+}
+
+/// Part 2 reads the seeds as (start, length) pairs
+fn seed_pairs(input: &str) -> Vec<Finding> {
+    let count = input
+        .lines()
+        .next()
+        .map_or(0, |line| line.split_ascii_whitespace().skip(1).count());
+    if count.is_multiple_of(2) {
+        vec![]
+    } else {
+        vec![(
+            Some(0),
+            format!("{count} seeds can't be read as ranges, part 2 will drop the last one"),
+        )]
+    }
+}
+
+/// Part 2 looks for the lowest location of the seeds in the ranges, so there has to be
+/// at least one
+fn seed_ranges(input: &str) -> Vec<Finding> {
+    // a line that isn't a list of seeds is up to `seeds` and too large numbers are up to
+    // `numbers-fit`
+    let Some(seeds) = input
+        .lines()
+        .next()
+        .filter(|line| SEEDS_RE.is_match(line))
+        .and_then(|line| {
+            line.split_ascii_whitespace()
+                .skip(1)
+                .map(|n| n.parse::<usize>().ok())
+                .collect::<Option<Vec<_>>>()
+        })
+    else {
+        return vec![];
+    };
+    if seeds.chunks_exact(2).any(|pair| pair[1] > 0) {
+        vec![]
+    } else {
+        vec![(
+            Some(0),
+            "there's no seed range with any seeds in it for part 2".to_string(),
+        )]
+    }
+}
+
+fn map_syntax(input: &str) -> Vec<Finding> {
+    let blocks = blocks(input);
+    if blocks.is_empty() {
+        return vec![(None, "no maps".to_string())];
+    }
+    blocks
+        .into_iter()
+        .flat_map(|block| {
+            let header = (!HEADER_RE.is_match(block.header)).then(|| {
+                (
+                    Some(block.header_idx),
+                    format!("expected a map header but found {:?}", block.header),
+                )
+            });
+            let empty = block
+                .ranges
+                .is_empty()
+                .then(|| (Some(block.header_idx), "map has no ranges".to_string()));
+            let ranges = block
+                .ranges
+                .into_iter()
+                .filter(|(_, line)| {
+                    RANGE_RE.captures(line).is_none_or(|cap| {
+                        ["dest_start", "source_start", "len"]
+                            .into_iter()
+                            .any(|key| cap[key].parse::<usize>().is_err())
+                    })
+                })
+                .map(|(idx, line)| (Some(idx), format!("not a valid range: {line:?}")));
+            header.into_iter().chain(empty).chain(ranges).collect_vec()
+        })
+        .collect()
+}
+
+/// The maps get applied in the order they're listed so they have to lead from seeds to
+/// locations in a single chain
+fn category_chain(input: &str) -> Vec<Finding> {
+    let headers = blocks(input)
+        .into_iter()
+        .filter_map(|block| {
+            let cap = HEADER_RE.captures(block.header)?;
+            Some((
+                block.header_idx,
+                cap["source"].to_string(),
+                cap["dest"].to_string(),
+            ))
+        })
+        .collect_vec();
+    let mut findings = vec![];
+    let mut expected_source = "seed".to_string();
+    for (idx, source, dest) in &headers {
+        if *source != expected_source {
+            findings.push((
+                Some(*idx),
+                format!("map starts at {source} but the previous one ended at {expected_source}"),
+            ));
+        }
+        expected_source = dest.clone();
+    }
+    if !headers.is_empty() && expected_source != "location" {
+        findings.push((
+            None,
+            format!("the last map ends at {expected_source} instead of location"),
+        ));
+    }
+    findings
+}
+
+/// A source value that's covered by multiple ranges has an ambiguous destination (ranges
+/// whose end overflows are left to `ranges-fit`)
+fn non_overlapping(input: &str) -> Vec<Finding> {
+    blocks(input)
+        .into_iter()
+        .flat_map(|block| {
+            block
+                .ranges
+                .into_iter()
+                .filter_map(|(idx, line)| {
+                    let cap = RANGE_RE.captures(line)?;
+                    let start = cap["source_start"].parse::<usize>().ok()?;
+                    let len = cap["len"].parse::<usize>().ok()?;
+                    Some((idx, start, start.checked_add(len)?))
+                })
+                .sorted_by_key(|&(_, start, _)| start)
+                .tuple_windows()
+                .filter(|((_, _, end), (_, start, _))| end > start)
+                .map(|((l_idx, _, _), (r_idx, _, _))| {
+                    (
+                        Some(r_idx),
+                        format!("source range overlaps the one on line {}", l_idx + 1),
+                    )
+                })
+                .collect_vec()
+        })
+        .collect()
+}
+
+/// The solvers work with the ends of the seed and map ranges, so start + length has to
+/// fit
+fn ranges_fit(input: &str) -> Vec<Finding> {
+    let seeds = input.lines().next().and_then(|line| {
+        let numbers = line
+            .split_ascii_whitespace()
+            .skip(1)
+            .map(|n| n.parse::<usize>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        numbers
+            .chunks_exact(2)
+            .find(|pair| pair[0].checked_add(pair[1]).is_none())
+            .map(|pair| {
+                (
+                    Some(0),
+                    format!("the seed range {} {} overflows", pair[0], pair[1]),
+                )
+            })
+    });
+    let ranges = blocks(input).into_iter().flat_map(|block| {
+        block.ranges.into_iter().filter_map(|(idx, line)| {
+            let cap = RANGE_RE.captures(line)?;
+            let [dest_start, source_start, len] =
+                ["dest_start", "source_start", "len"].map(|key| cap[key].parse::<usize>().ok());
+            let len = len?;
+            let overflows =
+                |start: Option<usize>| start.is_some_and(|start| start.checked_add(len).is_none());
+            let which = match (overflows(dest_start), overflows(source_start)) {
+                (false, false) => return None,
+                (true, false) => "destination",
+                (false, true) => "source",
+                (true, true) => "destination and source",
+            };
+            Some((Some(idx), format!("the {which} range overflows")))
+        })
+    });
+    seeds.into_iter().chain(ranges).collect()
+}
@@ -0,0 +1,177 @@
+//! Animated GIF encoding, with our own LZW compression.
+//!
+//! All frames share one global palette. If they have more than 256 colors between them
+//! they're reduced to 3 bits of red and green and 2 of blue, which is plenty for the
+//! flat colors of our visualizations.
+
+use std::collections::HashMap;
+
+use super::{Color, Frame};
+
+/// Longest code the LZW compression may use
+const MAX_CODE_SIZE: u8 = 12;
+
+/// The palette of a set of frames and each pixel's index into it
+fn palettize(frames: &[Frame]) -> (Vec<Color>, Vec<Vec<u8>>) {
+    let mut palette = frames
+        .iter()
+        .flat_map(|frame| &frame.pixels)
+        .copied()
+        .collect::<Vec<_>>();
+    palette.sort();
+    palette.dedup();
+    let reduce: fn(Color) -> Color = if palette.len() > 256 {
+        |Color(r, g, b)| Color(r & 0xe0, g & 0xe0, b & 0xc0)
+    } else {
+        |color| color
+    };
+    palette = palette.into_iter().map(reduce).collect();
+    palette.sort();
+    palette.dedup();
+    let index = palette
+        .iter()
+        .enumerate()
+        .map(|(i, color)| (*color, i as u8))
+        .collect::<HashMap<_, _>>();
+    let indices = frames
+        .iter()
+        .map(|frame| {
+            frame
+                .pixels
+                .iter()
+                .map(|pixel| index[&reduce(*pixel)])
+                .collect()
+        })
+        .collect();
+    (palette, indices)
+}
+
+/// Packs codes of varying lengths into bytes, least significant bit first
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// LZW-compress palette indices the way GIF wants it: starting with a clear code, with
+/// codes growing from `min_code_size + 1` bits and the table being cleared when it's full
+pub fn lzw(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut writer = BitWriter::default();
+    let mut size = min_code_size + 1;
+    let mut next = end + 1;
+    let mut table = HashMap::<(u16, u8), u16>::new();
+    writer.write(clear, size);
+
+    let mut pixels = indices.iter().copied();
+    let Some(first) = pixels.next() else {
+        writer.write(end, size);
+        return writer.finish();
+    };
+    let mut current = first as u16;
+    for pixel in pixels {
+        if let Some(&code) = table.get(&(current, pixel)) {
+            current = code;
+            continue;
+        }
+        writer.write(current, size);
+        if next == 1 << MAX_CODE_SIZE {
+            writer.write(clear, size);
+            table.clear();
+            size = min_code_size + 1;
+            next = end + 1;
+        } else {
+            table.insert((current, pixel), next);
+            next += 1;
+            // the decoder adds its entries a code later, so it only needs the longer
+            // codes from the one after this
+            if next > 1 << size && size < MAX_CODE_SIZE {
+                size += 1;
+            }
+        }
+        current = pixel as u16;
+    }
+    writer.write(current, size);
+    writer.write(end, size);
+    writer.finish()
+}
+
+/// Write data as sub-blocks of at most 255 bytes, ended by an empty one
+fn sub_blocks(out: &mut Vec<u8>, data: &[u8]) {
+    for block in data.chunks(255) {
+        out.push(block.len() as u8);
+        out.extend(block);
+    }
+    out.push(0);
+}
+
+/// Encode frames of the same size as an endlessly looping animation, showing each frame
+/// for `delay` hundredths of a second
+pub fn encode(frames: &[Frame], delay: u16) -> Vec<u8> {
+    let (width, height) = frames
+        .first()
+        .map_or((0, 0), |frame| (frame.width, frame.height));
+    assert!(
+        frames
+            .iter()
+            .all(|frame| (frame.width, frame.height) == (width, height)),
+        "all frames of a GIF need the same size"
+    );
+    let (mut palette, indices) = palettize(frames);
+    // the color table has a power of two entries, at least 2
+    let table_bits = (palette.len().max(2).next_power_of_two().trailing_zeros()) as u8;
+    palette.resize(1 << table_bits, Color::BLACK);
+
+    let mut out = b"GIF89a".to_vec();
+    out.extend((width as u16).to_le_bytes());
+    out.extend((height as u16).to_le_bytes());
+    // a global color table, its color resolution and its size
+    out.push(0x80 | ((table_bits - 1) << 4) | (table_bits - 1));
+    out.extend([0, 0]);
+    for color in &palette {
+        out.extend([color.0, color.1, color.2]);
+    }
+    // loop forever
+    out.extend([0x21, 0xff, 11]);
+    out.extend(b"NETSCAPE2.0");
+    out.extend([3, 1, 0, 0, 0]);
+
+    let min_code_size = table_bits.max(2);
+    for indices in &indices {
+        // graphic control extension with the delay
+        out.extend([0x21, 0xf9, 4, 0]);
+        out.extend(delay.to_le_bytes());
+        out.extend([0, 0]);
+        // image descriptor covering the whole screen, without a local color table
+        out.push(0x2c);
+        out.extend([0, 0, 0, 0]);
+        out.extend((width as u16).to_le_bytes());
+        out.extend((height as u16).to_le_bytes());
+        out.push(0);
+        out.push(min_code_size);
+        sub_blocks(&mut out, &lzw(indices, min_code_size));
+    }
+    out.push(0x3b);
+    out
+}
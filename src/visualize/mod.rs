@@ -0,0 +1,189 @@
+//! Visualizations of how the solutions work, as sequences of frames.
+//!
+//! Anything implementing [`Visualize`] draws itself into [`Frame`]s - plain RGB pixel
+//! buffers with one pixel per puzzle cell (or value bucket), which are scaled up when
+//! they're exported. The exporters are our own (see [`ppm`], [`png`] and [`gif`]), so
+//! this runs anywhere without any image libraries or a display:
+//!
+//! ```sh
+//! visualize 3 --format gif --scale 4 --out schematic.gif
+//! ```
+//!
+//! The same frames can also be played in the [terminal](terminal).
+//!
+//! Puzzle structures that are better looked at as a whole than as an animation have an
+//! [`svg`] drawing, via [`ToSvg`] (`visualize 2 --format svg`).
+
+pub mod gif;
+pub mod png;
+pub mod ppm;
+pub mod svg;
+pub mod terminal;
+pub mod y2023;
+
+use std::{fs, io, path::Path, str::FromStr};
+
+use crate::y2023::{day_02, day_03, day_05};
+
+pub use svg::{Svg, ToSvg};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const BACKGROUND: Self = Self(15, 15, 35);
+    pub const DIM: Self = Self(70, 70, 90);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const RED: Self = Self(220, 50, 47);
+    pub const GREEN: Self = Self(0, 204, 0);
+    pub const YELLOW: Self = Self(255, 255, 102);
+    pub const BLUE: Self = Self(38, 139, 210);
+
+    /// Distinct colors for telling a handful of things apart, like the ranges of a map
+    pub const PALETTE: [Self; 8] = [
+        Self(38, 139, 210),
+        Self(211, 54, 130),
+        Self(133, 153, 0),
+        Self(203, 75, 22),
+        Self(108, 113, 196),
+        Self(42, 161, 152),
+        Self(181, 137, 0),
+        Self(220, 50, 47),
+    ];
+
+    /// Mix with another color, `amount` being how much of the other one (0 to 1)
+    pub fn mix(self, other: Self, amount: f64) -> Self {
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * amount).round() as u8;
+        Self(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// An RGB image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub width: usize,
+    pub height: usize,
+    /// Row by row, from the top left
+    pub pixels: Vec<Color>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Color {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Set a pixel, ignoring ones outside of the frame
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    /// Fill the rectangle with its top left corner at `(x, y)`, clipped to the frame
+    pub fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, color: Color) {
+        for y in y..(y + height).min(self.height) {
+            for x in x..(x + width).min(self.width) {
+                self.pixels[y * self.width + x] = color;
+            }
+        }
+    }
+
+    /// Blow every pixel up to a `factor` by `factor` square
+    pub fn scaled(&self, factor: usize) -> Self {
+        let mut scaled = Self::new(self.width * factor, self.height * factor, Color::BLACK);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                scaled.fill(x * factor, y * factor, factor, factor, self.get(x, y));
+            }
+        }
+        scaled
+    }
+}
+
+/// Something that can show what's going on while it's being solved
+pub trait Visualize {
+    fn frames(&self) -> Vec<Frame>;
+}
+
+/// The frames of a day's visualization of an input, if it has one
+pub fn frames(year: usize, day: usize, input: &str) -> Option<Vec<Frame>> {
+    match (year, day) {
+        (2023, 3) => Some(input.parse::<day_03::Schematic>().unwrap().frames()),
+        (2023, 4) => Some(input.parse::<y2023::day_04::Cascade>().unwrap().frames()),
+        (2023, 5) => Some(input.parse::<day_05::Almanac>().unwrap().frames()),
+        _ => None,
+    }
+}
+
+/// The SVG drawing of a day's input, if it has one
+pub fn drawing(year: usize, day: usize, input: &str) -> Option<Svg> {
+    match (year, day) {
+        (2023, 2) => Some(
+            input
+                .lines()
+                .map(|line| line.parse::<day_02::Game>().unwrap())
+                .collect::<Vec<_>>()
+                .to_svg(),
+        ),
+        (2023, 3) => Some(input.parse::<day_03::Schematic>().unwrap().to_svg()),
+        (2023, 5) => Some(input.parse::<day_05::Almanac>().unwrap().to_svg()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A numbered `.ppm` file per frame
+    Ppm,
+    /// A numbered `.png` file per frame
+    Png,
+    /// One animated `.gif`
+    Gif,
+}
+
+impl FromStr for Format {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(Self::Ppm),
+            "png" => Ok(Self::Png),
+            "gif" => Ok(Self::Gif),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Write frames in some format: sequences go into the directory `out` as
+/// `frame_0000.ppm` and so on, a GIF to the file `out` with `delay` hundredths of a
+/// second per frame
+pub fn export(frames: &[Frame], format: Format, out: &Path, delay: u16) -> io::Result<()> {
+    let sequence = |extension: &str, encode: fn(&Frame) -> Vec<u8>| {
+        fs::create_dir_all(out)?;
+        frames.iter().enumerate().try_for_each(|(i, frame)| {
+            fs::write(out.join(format!("frame_{i:04}.{extension}")), encode(frame))
+        })
+    };
+    match format {
+        Format::Ppm => sequence("ppm", ppm::encode),
+        Format::Png => sequence("png", png::encode),
+        Format::Gif => {
+            if let Some(dir) = out.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(out, gif::encode(frames, delay))
+        }
+    }
+}
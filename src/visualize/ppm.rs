@@ -0,0 +1,11 @@
+//! Binary PPM (`P6`), the simplest image format there is.
+
+use super::Frame;
+
+pub fn encode(frame: &Frame) -> Vec<u8> {
+    let mut out = format!("P6\n{} {}\n255\n", frame.width, frame.height).into_bytes();
+    for pixel in &frame.pixels {
+        out.extend([pixel.0, pixel.1, pixel.2]);
+    }
+    out
+}
@@ -0,0 +1,174 @@
+//! SVG drawings of the puzzle structures.
+//!
+//! The drawings are standalone files: everything is inline, text uses the generic
+//! `monospace` family rather than any font that would have to be loaded, and there are
+//! no scripts. Details like the values behind a shape are in `<title>`s, which viewers
+//! show as tooltips.
+
+use std::fmt::{self, Display};
+
+use super::Color;
+
+/// Escape text for use in XML
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Color {
+    /// `#rrggbb`
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// An SVG element with its attributes and what's inside
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    name: &'static str,
+    attrs: Vec<(&'static str, String)>,
+    title: Option<String>,
+    text: Option<String>,
+}
+
+impl Element {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            attrs: vec![],
+            title: None,
+            text: None,
+        }
+    }
+
+    pub fn attr(mut self, key: &'static str, value: impl Display) -> Self {
+        self.attrs.push((key, value.to_string()));
+        self
+    }
+
+    pub fn fill(self, color: Color) -> Self {
+        self.attr("fill", color.hex())
+    }
+
+    pub fn stroke(self, color: Color, width: f64) -> Self {
+        self.attr("stroke", color.hex()).attr("stroke-width", width)
+    }
+
+    /// A tooltip
+    pub fn title(mut self, title: impl Display) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// The text of a `<text>` element
+    pub fn text(mut self, text: impl Display) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}", self.name)?;
+        for (key, value) in &self.attrs {
+            write!(f, " {key}=\"{}\"", escape(value))?;
+        }
+        if self.title.is_none() && self.text.is_none() {
+            return write!(f, "/>");
+        }
+        write!(f, ">")?;
+        if let Some(title) = &self.title {
+            write!(f, "<title>{}</title>", escape(title))?;
+        }
+        if let Some(text) = &self.text {
+            write!(f, "{}", escape(text))?;
+        }
+        write!(f, "</{}>", self.name)
+    }
+}
+
+pub fn rect(x: f64, y: f64, width: f64, height: f64) -> Element {
+    Element::new("rect")
+        .attr("x", x)
+        .attr("y", y)
+        .attr("width", width)
+        .attr("height", height)
+}
+
+pub fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Element {
+    Element::new("line")
+        .attr("x1", x1)
+        .attr("y1", y1)
+        .attr("x2", x2)
+        .attr("y2", y2)
+}
+
+/// A line with an arrowhead at its end
+pub fn arrow(x1: f64, y1: f64, x2: f64, y2: f64) -> Element {
+    line(x1, y1, x2, y2).attr("marker-end", "url(#arrow)")
+}
+
+/// Text with its baseline starting at a point (or centered on it, or ending there, with
+/// an `anchor` of `middle` or `end`)
+pub fn text(x: f64, y: f64, size: f64, anchor: &str, content: impl Display) -> Element {
+    Element::new("text")
+        .attr("x", x)
+        .attr("y", y)
+        .attr("font-size", size)
+        .attr("text-anchor", anchor)
+        .text(content)
+}
+
+/// A drawing
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    pub width: f64,
+    pub height: f64,
+    pub background: Color,
+    pub elements: Vec<Element>,
+}
+
+impl Svg {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            background: Color::BACKGROUND,
+            elements: vec![],
+        }
+    }
+
+    pub fn push(&mut self, element: Element) {
+        self.elements.push(element);
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, height) = (self.width, self.height);
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" font-family=\"monospace\">"
+        )?;
+        writeln!(
+            f,
+            "<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" \
+             markerWidth=\"6\" markerHeight=\"6\" orient=\"auto-start-reverse\">\
+             <path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"{}\"/></marker></defs>",
+            Color::WHITE.hex()
+        )?;
+        writeln!(f, "{}", rect(0.0, 0.0, width, height).fill(self.background))?;
+        for element in &self.elements {
+            writeln!(f, "{element}")?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+/// Something that can be drawn as an SVG
+pub trait ToSvg {
+    fn to_svg(&self) -> Svg;
+}
@@ -0,0 +1,265 @@
+//! Playing frames as an animation in the terminal.
+//!
+//! Every character cell shows two pixels, stacked with a half block in 24-bit color, and
+//! the frames are drawn in place on the alternate screen. The keys are read from the
+//! terminal while it's playing:
+//!
+//! * `space` pauses and resumes
+//! * `n` or `→` steps to the next frame and `p` or `←` back to the previous one (both
+//!   also pause)
+//! * `+` and `-` double and halve the speed
+//! * `q` quits
+//!
+//! Without a terminal to read keys from it just plays every frame once.
+
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, Read, Write},
+    process::{Command as Process, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use super::{Color, Frame};
+
+/// The terminal, wherever stdin and stdout go
+const TTY: &str = "/dev/tty";
+const SLOWEST: f64 = 0.125;
+const FASTEST: f64 = 16.0;
+
+/// Render a frame as lines of half blocks, two rows of pixels per line
+pub fn render(frame: &Frame) -> String {
+    let mut out = String::new();
+    for y in (0..frame.height).step_by(2) {
+        let mut colors: Option<(Color, Option<Color>)> = None;
+        for x in 0..frame.width {
+            let top = frame.get(x, y);
+            let bottom = (y + 1 < frame.height).then(|| frame.get(x, y + 1));
+            // only switch colors when they change
+            if colors != Some((top, bottom)) {
+                let Color(r, g, b) = top;
+                write!(out, "\x1b[38;2;{r};{g};{b}m").unwrap();
+                match bottom {
+                    Some(Color(r, g, b)) => write!(out, "\x1b[48;2;{r};{g};{b}m").unwrap(),
+                    None => out.push_str("\x1b[49m"),
+                }
+                colors = Some((top, bottom));
+            }
+            out.push('▀');
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+/// What the keys do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    TogglePause,
+    Step,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Command {
+    /// The commands typed in some bytes read from the terminal (arrow keys come as escape
+    /// sequences); anything else is ignored
+    pub fn parse(bytes: &[u8]) -> Vec<Self> {
+        let mut commands = vec![];
+        let mut rest = bytes;
+        while let Some((&byte, tail)) = rest.split_first() {
+            rest = tail;
+            let command = match byte {
+                b' ' => Self::TogglePause,
+                b'n' => Self::Step,
+                b'p' => Self::Back,
+                b'+' | b'=' => Self::Faster,
+                b'-' => Self::Slower,
+                b'q' => Self::Quit,
+                0x1b => match rest {
+                    [b'[', b'C', tail @ ..] => {
+                        rest = tail;
+                        Self::Step
+                    }
+                    [b'[', b'D', tail @ ..] => {
+                        rest = tail;
+                        Self::Back
+                    }
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            commands.push(command);
+        }
+        commands
+    }
+}
+
+/// Where an animation is at
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub frames: Vec<Frame>,
+    /// How long a frame is shown at normal speed
+    pub delay: Duration,
+    pub index: usize,
+    pub paused: bool,
+    pub speed: f64,
+}
+
+impl Player {
+    pub fn new(frames: Vec<Frame>, delay: Duration) -> Self {
+        assert!(!frames.is_empty(), "nothing to play");
+        Self {
+            frames,
+            delay,
+            index: 0,
+            paused: false,
+            speed: 1.0,
+        }
+    }
+
+    /// How long the current frame is shown at the current speed
+    pub fn wait(&self) -> Duration {
+        self.delay.div_f64(self.speed)
+    }
+
+    /// Go to the next frame, starting over after the last one
+    pub fn advance(&mut self) {
+        self.index = (self.index + 1) % self.frames.len();
+    }
+
+    /// Carry out a command, returning whether to go on playing
+    pub fn apply(&mut self, command: Command) -> bool {
+        match command {
+            Command::TogglePause => self.paused = !self.paused,
+            Command::Step => {
+                self.paused = true;
+                self.advance();
+            }
+            Command::Back => {
+                self.paused = true;
+                self.index = (self.index + self.frames.len() - 1) % self.frames.len();
+            }
+            Command::Faster => self.speed = (self.speed * 2.0).min(FASTEST),
+            Command::Slower => self.speed = (self.speed / 2.0).max(SLOWEST),
+            Command::Quit => return false,
+        }
+        true
+    }
+
+    /// The current frame and a status line, drawn over the previous ones
+    pub fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            out,
+            "\x1b[H{}\x1b[0mframe {}/{}  {}x{}  \
+             (space: pause, n/p: step, +/-: speed, q: quit)\x1b[K",
+            render(&self.frames[self.index]),
+            self.index + 1,
+            self.frames.len(),
+            self.speed,
+            if self.paused { "  paused" } else { "" },
+        )?;
+        out.flush()
+    }
+
+    /// Play until told to quit. Once there's no one left to send commands it plays to
+    /// the last frame and stops.
+    pub fn play(&mut self, out: &mut impl Write, commands: &Receiver<Command>) -> io::Result<()> {
+        loop {
+            self.draw(out)?;
+            let command = if self.paused {
+                commands.recv().unwrap_or(Command::Quit)
+            } else {
+                match commands.recv_timeout(self.wait()) {
+                    Ok(command) => command,
+                    Err(RecvTimeoutError::Timeout) => {
+                        self.advance();
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        if self.index + 1 == self.frames.len() {
+                            return Ok(());
+                        }
+                        thread::sleep(self.wait());
+                        self.advance();
+                        continue;
+                    }
+                }
+            };
+            if !self.apply(command) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Puts the terminal into a mode where keys are read as they're pressed and not echoed,
+/// until it's dropped
+struct RawMode {
+    saved: String,
+}
+
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Process::new("stty")
+        .args(args)
+        .stdin(File::open(TTY)?)
+        .stderr(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("stty failed"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "min", "1"])?;
+        Ok(Self { saved })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+/// Play frames on stdout, controlled by the keys typed into the terminal if there is one
+pub fn play(frames: Vec<Frame>, delay: Duration) -> io::Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let raw_mode = RawMode::enable().ok();
+    match (&raw_mode, File::open(TTY)) {
+        (Some(_), Ok(mut tty)) => {
+            thread::spawn(move || {
+                let mut buf = [0; 16];
+                while let Ok(len @ 1..) = tty.read(&mut buf) {
+                    for command in Command::parse(&buf[..len]) {
+                        if tx.send(command).is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+        _ => drop(tx),
+    }
+
+    // when it's interactive it happens on the alternate screen, otherwise the last frame
+    // is left standing
+    let (enter, leave) = match raw_mode {
+        Some(_) => ("\x1b[?1049h", "\x1b[?1049l"),
+        None => ("", ""),
+    };
+    let mut out = io::stdout().lock();
+    write!(out, "{enter}\x1b[2J\x1b[?25l")?;
+    let result = Player::new(frames, delay).play(&mut out, &rx);
+    write!(out, "\x1b[?25h{leave}")?;
+    out.flush()?;
+    drop(raw_mode);
+    result
+}
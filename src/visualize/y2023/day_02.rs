@@ -0,0 +1,103 @@
+//! The fewest cubes of each color every game needs, as a bar per color, against dashed
+//! lines for the bag of part one. Games that bag can't be played with are labeled in
+//! red, and the bars going over it are outlined.
+
+use crate::{
+    visualize::{
+        svg::{self, Svg, ToSvg},
+        Color,
+    },
+    y2023::day_02::{self, Bag, Game},
+};
+
+/// Width of the game labels
+const LABEL: f64 = 72.0;
+/// Width of one cube
+const CUBE: f64 = 10.0;
+const BAR: f64 = 6.0;
+/// Height of the header with the limits and of the space between games
+const GAP: f64 = 16.0;
+const ROW: f64 = 3.0 * BAR + GAP;
+
+const COLORS: [(day_02::Color, Color, &str); 3] = [
+    (day_02::Color::Red, Color::RED, "red"),
+    (day_02::Color::Green, Color::GREEN, "green"),
+    (day_02::Color::Blue, Color::BLUE, "blue"),
+];
+
+impl ToSvg for [Game] {
+    fn to_svg(&self) -> Svg {
+        let allowed = Bag::allowed();
+        let bags = self
+            .iter()
+            .map(Game::minimal_possible_bag)
+            .collect::<Vec<_>>();
+        let most = bags
+            .iter()
+            .chain([&allowed])
+            .flat_map(|bag| bag.0.values())
+            .copied()
+            .max()
+            .unwrap_or(0);
+        // room for the count next to the longest bar
+        let width = LABEL + (most + 4) as f64 * CUBE;
+        let height = GAP + self.len() as f64 * ROW;
+        let mut svg = Svg::new(width, height);
+
+        for (k, (color, fill, name)) in COLORS.into_iter().enumerate() {
+            let limit = allowed.0[&color];
+            let x = LABEL + limit as f64 * CUBE;
+            svg.push(
+                svg::line(x, GAP - 4.0, x, height)
+                    .stroke(fill, 1.0)
+                    .attr("stroke-dasharray", "3 3")
+                    .title(format!("at most {limit} {name}")),
+            );
+            // the limits are next to each other, so they're labeled on alternating sides
+            let anchor = ["end", "middle", "start"][k];
+            svg.push(svg::text(x, GAP - 6.0, 9.0, anchor, limit).fill(fill));
+        }
+
+        for (i, (game, bag)) in self.iter().zip(&bags).enumerate() {
+            let top = GAP + i as f64 * ROW;
+            let possible = game.is_possible(&allowed);
+            svg.push(
+                svg::text(
+                    0.0,
+                    top + 2.0 * BAR,
+                    10.0,
+                    "start",
+                    format!("Game {}", game.game_id),
+                )
+                .fill(if possible { Color::WHITE } else { Color::RED })
+                .title(format!(
+                    "{}possible with the bag, power {}",
+                    if possible { "" } else { "not " },
+                    bag.power()
+                )),
+            );
+            for (k, (color, fill, name)) in COLORS.into_iter().enumerate() {
+                let count = bag.0[&color];
+                let y = top + k as f64 * BAR;
+                let mut bar = svg::rect(LABEL, y, count as f64 * CUBE, BAR - 1.0)
+                    .fill(fill)
+                    .title(format!("Game {}: at least {count} {name}", game.game_id));
+                if count > allowed.0[&color] {
+                    bar = bar.stroke(Color::WHITE, 1.0);
+                }
+                svg.push(bar);
+                svg.push(
+                    svg::text(
+                        LABEL + count as f64 * CUBE + 3.0,
+                        y + BAR - 1.0,
+                        6.0,
+                        "start",
+                        count,
+                    )
+                    .fill(Color::DIM.mix(Color::WHITE, 0.5)),
+                );
+            }
+        }
+        svg
+    }
+}
@@ -0,0 +1,165 @@
+//! The schematic with one pixel per character, and the part numbers lighting up line by
+//! line as the symbols next to them are found: green for part numbers, yellow for gears
+//! and their two numbers.
+//!
+//! The SVG is the schematic as it ends up, with its characters: the number spans as
+//! colored boxes and the symbols, the gears circled.
+
+use std::collections::BTreeSet;
+
+use crate::{
+    visualize::{
+        svg::{self, Element, Svg, ToSvg},
+        Color, Frame, Visualize,
+    },
+    y2023::day_03::{self, Schematic, SchematicEntry},
+};
+
+/// Size of a character in the SVG
+const CELL: f64 = 14.0;
+
+/// How many characters wide the schematic is, up to its last number or symbol
+fn width(schematic: &Schematic) -> usize {
+    schematic
+        .lines
+        .iter()
+        .flat_map(|line| &line.0)
+        .map(|entry| match entry {
+            SchematicEntry::Symbol(sym) => sym.idx + 1,
+            SchematicEntry::Number(num) => num.span.end() + 1,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+impl Visualize for Schematic {
+    fn frames(&self) -> Vec<Frame> {
+        let entries = self.lines.iter().flat_map(|line| &line.0);
+        let width = width(self);
+        let mut frame = Frame::new(width, self.lines.len(), Color::BACKGROUND);
+        for entry in entries {
+            match entry {
+                SchematicEntry::Symbol(sym) => frame.set(sym.idx, sym.line_idx, Color::RED),
+                SchematicEntry::Number(num) => frame.fill(
+                    *num.span.start(),
+                    num.line_idx,
+                    num.span.clone().count(),
+                    1,
+                    Color::DIM,
+                ),
+            }
+        }
+
+        let adjacencies = self.adjacencies();
+        let mut frames = vec![frame.clone()];
+        for line_idx in 0..self.lines.len() {
+            let symbols = adjacencies
+                .iter()
+                .filter(|(sym, _)| sym.line_idx == line_idx)
+                .collect::<Vec<_>>();
+            if symbols.is_empty() {
+                continue;
+            }
+            for (sym, nums) in symbols {
+                let gear = day_03::try_get_gear(sym, nums).is_some();
+                frame.set(
+                    sym.idx,
+                    sym.line_idx,
+                    if gear { Color::YELLOW } else { Color::WHITE },
+                );
+                for num in nums {
+                    let start = *num.span.start();
+                    // a number next to a gear stays yellow
+                    if gear || frame.get(start, num.line_idx) != Color::YELLOW {
+                        let color = if gear { Color::YELLOW } else { Color::GREEN };
+                        frame.fill(start, num.line_idx, num.span.clone().count(), 1, color);
+                    }
+                }
+            }
+            frames.push(frame.clone());
+        }
+        frames
+    }
+}
+
+impl ToSvg for Schematic {
+    fn to_svg(&self) -> Svg {
+        let adjacencies = self.adjacencies();
+        let gears = adjacencies
+            .iter()
+            .filter_map(|(sym, nums)| day_03::try_get_gear(sym, nums))
+            .collect::<Vec<_>>();
+        let gear_nums = gears
+            .iter()
+            .flat_map(|(_, nums)| *nums)
+            .collect::<BTreeSet<_>>();
+        let part_nums = adjacencies
+            .values()
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        let mut svg = Svg::new(width(self) as f64 * CELL, self.lines.len() as f64 * CELL);
+        for entry in self.lines.iter().flat_map(|line| &line.0) {
+            match entry {
+                SchematicEntry::Number(num) => {
+                    let (color, what) = if gear_nums.contains(num) {
+                        (Color::YELLOW, "part of a gear")
+                    } else if part_nums.contains(num) {
+                        (Color::GREEN, "part number")
+                    } else {
+                        (Color::DIM, "not a part number")
+                    };
+                    let (x, y) = (*num.span.start() as f64 * CELL, num.line_idx as f64 * CELL);
+                    let len = num.span.clone().count() as f64 * CELL;
+                    svg.push(
+                        svg::rect(x + 1.0, y + 1.0, len - 2.0, CELL - 2.0)
+                            .fill(color.mix(Color::BACKGROUND, 0.6))
+                            .stroke(color, 1.0)
+                            .attr("rx", 2)
+                            .title(format!("{} ({what})", num.val)),
+                    );
+                    // stretched over the span, so the digits line up with the grid
+                    svg.push(
+                        svg::text(x + 3.0, y + CELL * 0.75, CELL * 0.8, "start", num.val)
+                            .attr("textLength", len - 6.0)
+                            .attr("lengthAdjust", "spacing")
+                            .fill(Color::WHITE),
+                    );
+                }
+                SchematicEntry::Symbol(sym) => {
+                    let (x, y) = (
+                        (sym.idx as f64 + 0.5) * CELL,
+                        (sym.line_idx as f64 + 0.5) * CELL,
+                    );
+                    let gear = gears.iter().find(|(gear, _)| *gear == sym);
+                    let color = if let Some((_, [a, b])) = gear {
+                        svg.push(
+                            Element::new("circle")
+                                .attr("cx", x)
+                                .attr("cy", y)
+                                .attr("r", CELL / 2.0)
+                                .attr("fill", "none")
+                                .stroke(Color::YELLOW, 1.0)
+                                .title(format!(
+                                    "gear ratio {} * {} = {}",
+                                    a.val,
+                                    b.val,
+                                    a.val * b.val
+                                )),
+                        );
+                        Color::YELLOW
+                    } else if adjacencies.contains_key(sym) {
+                        Color::WHITE
+                    } else {
+                        Color::RED
+                    };
+                    svg.push(
+                        svg::text(x, y + CELL * 0.3, CELL * 0.8, "middle", sym.val).fill(color),
+                    );
+                }
+            }
+        }
+        svg
+    }
+}
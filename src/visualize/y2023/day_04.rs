@@ -0,0 +1,75 @@
+//! The cascade of card copies in part 2 as a bar chart with a bar per card, growing as
+//! every card in turn wins copies of the ones after it. The bars are on a log scale as
+//! the copies grow exponentially.
+
+use std::str::FromStr;
+
+use crate::{
+    visualize::{Color, Frame, Visualize},
+    y2023::day_04::Card,
+};
+
+/// Height of the chart in pixels
+const HEIGHT: usize = 64;
+
+/// The numbers of wins of all cards
+pub struct Cascade {
+    pub wins: Vec<usize>,
+}
+
+impl FromStr for Cascade {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            wins: input
+                .lines()
+                .map(|line| line.parse::<Card>().unwrap().count_wins())
+                .collect(),
+        })
+    }
+}
+
+impl Cascade {
+    /// The copies of every card after scratching the first `scratched` cards
+    pub fn copies(&self, scratched: usize) -> Vec<u64> {
+        let mut copies = vec![1u64; self.wins.len()];
+        for (card, wins) in self.wins.iter().enumerate().take(scratched) {
+            for won in card + 1..=(card + wins).min(self.wins.len() - 1) {
+                copies[won] = copies[won].saturating_add(copies[card]);
+            }
+        }
+        copies
+    }
+}
+
+impl Visualize for Cascade {
+    fn frames(&self) -> Vec<Frame> {
+        let max = self.copies(self.wins.len()).into_iter().max().unwrap_or(1);
+        let height = |copies: u64| {
+            let scale = ((copies as f64).log2() + 1.0) / ((max as f64).log2() + 1.0);
+            ((scale * HEIGHT as f64).round() as usize).clamp(1, HEIGHT)
+        };
+        (0..=self.wins.len())
+            .map(|scratched| {
+                let mut frame = Frame::new(self.wins.len(), HEIGHT, Color::BACKGROUND);
+                for (card, copies) in self.copies(scratched).into_iter().enumerate() {
+                    let color = match card {
+                        // the card that was just scratched and the ones it won
+                        _ if card + 1 == scratched => Color::WHITE,
+                        _ if scratched > 0
+                            && card >= scratched
+                            && card < scratched + self.wins[scratched - 1] =>
+                        {
+                            Color::GREEN
+                        }
+                        _ if card < scratched => Color::BLUE,
+                        _ => Color::DIM,
+                    };
+                    let bar = height(copies);
+                    frame.fill(card, HEIGHT - bar, 1, bar, color);
+                }
+                frame
+            })
+            .collect()
+    }
+}
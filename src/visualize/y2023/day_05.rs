@@ -0,0 +1,163 @@
+//! The maps of the almanac, one per frame: the source ranges along the top, where they
+//! end up along the bottom and a band between each pair. The seeds are the white lines
+//! going through the maps.
+//!
+//! The SVG has all the maps stacked on top of each other, each with its ranges as bars
+//! and arrows from the source ranges to their destinations, and the seeds as ticks.
+
+use crate::{
+    visualize::{
+        svg::{self, Svg, ToSvg},
+        Color, Frame, Visualize,
+    },
+    y2023::day_05::Almanac,
+};
+
+const WIDTH: usize = 256;
+/// Height of the bars of source and destination ranges
+const BAR: usize = 8;
+/// Height of the bands connecting them
+const LINK: usize = 48;
+
+/// The largest value anything in the almanac gets to, so they can all be scaled to it
+fn max(almanac: &Almanac) -> usize {
+    almanac
+        .maps
+        .iter()
+        .flat_map(|(_, map)| &map.0)
+        .flat_map(|range| [range.source_end(), range.dest_end()])
+        .chain(almanac.seeds.iter().map(|seed| seed + 1))
+        .chain(almanac.seeds.iter().flat_map(|seed| almanac.trace(*seed)))
+        .max()
+        .unwrap_or(1)
+        .max(1)
+}
+
+/// Each seed followed by its value after every map
+fn traces(almanac: &Almanac) -> Vec<Vec<usize>> {
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| {
+            let mut trace = vec![*seed];
+            trace.extend(almanac.trace(*seed));
+            trace
+        })
+        .collect()
+}
+
+impl Visualize for Almanac {
+    fn frames(&self) -> Vec<Frame> {
+        let max = max(self);
+        let x = |value: usize| (value as f64 / max as f64 * (WIDTH - 1) as f64).round();
+        let traces = traces(self);
+
+        self.maps
+            .iter()
+            .enumerate()
+            .map(|(i, (_, map))| {
+                let mut frame = Frame::new(WIDTH, 2 * BAR + LINK, Color::BACKGROUND);
+                // a band from `from` along the top to `to` along the bottom
+                let mut band = |from: [f64; 2], to: [f64; 2], bar: Color, link: Color| {
+                    let width = |[start, end]: [f64; 2]| (end - start).max(1.0) as usize;
+                    frame.fill(from[0] as usize, 0, width(from), BAR, bar);
+                    frame.fill(to[0] as usize, BAR + LINK, width(to), BAR, bar);
+                    for y in 0..LINK {
+                        let t = y as f64 / (LINK - 1) as f64;
+                        let lerp = |k: usize| from[k] + (to[k] - from[k]) * t;
+                        let row = [lerp(0), lerp(1)];
+                        frame.fill(row[0] as usize, BAR + y, width(row), 1, link);
+                    }
+                };
+                for (k, range) in map.0.iter().enumerate() {
+                    let color = Color::PALETTE[k % Color::PALETTE.len()];
+                    band(
+                        [x(range.source_start), x(range.source_end())],
+                        [x(range.dest_start), x(range.dest_end())],
+                        color,
+                        color.mix(Color::BACKGROUND, 0.5),
+                    );
+                }
+                for trace in &traces {
+                    let (from, to) = (x(trace[i]), x(trace[i + 1]));
+                    band([from, from], [to, to], Color::WHITE, Color::WHITE);
+                }
+                frame
+            })
+            .collect()
+    }
+}
+
+/// Width of the number line in the SVG
+const SVG_WIDTH: f64 = 800.0;
+const MARGIN: f64 = 12.0;
+/// Heights in the SVG of a map's name, its bars and the arrows between them
+const NAME: f64 = 18.0;
+const SVG_BAR: f64 = 10.0;
+const ARROWS: f64 = 48.0;
+const MAP: f64 = NAME + 2.0 * SVG_BAR + ARROWS + MARGIN;
+
+impl ToSvg for Almanac {
+    fn to_svg(&self) -> Svg {
+        let max = max(self);
+        let x = |value: usize| MARGIN + value as f64 / max as f64 * SVG_WIDTH;
+        let traces = traces(self);
+        let mut svg = Svg::new(
+            SVG_WIDTH + 2.0 * MARGIN,
+            MARGIN + self.maps.len() as f64 * MAP,
+        );
+        for (i, (name, map)) in self.maps.iter().enumerate() {
+            let top = MARGIN + i as f64 * MAP;
+            let (source, dest) = (top + NAME, top + NAME + SVG_BAR + ARROWS);
+            svg.push(svg::text(MARGIN, top + 12.0, 12.0, "start", name).fill(Color::WHITE));
+            // what's outside of the ranges stays where it is
+            for y in [source, dest] {
+                svg.push(
+                    svg::line(x(0), y + SVG_BAR / 2.0, x(max), y + SVG_BAR / 2.0)
+                        .stroke(Color::DIM, 1.0),
+                );
+            }
+            for (k, range) in map.0.iter().enumerate() {
+                let color = Color::PALETTE[k % Color::PALETTE.len()];
+                let title = format!(
+                    "{name}: {}..{} to {}..{}",
+                    range.source_start,
+                    range.source_end(),
+                    range.dest_start,
+                    range.dest_end()
+                );
+                let (from, to) = (
+                    [x(range.source_start), x(range.source_end())],
+                    [x(range.dest_start), x(range.dest_end())],
+                );
+                for ([start, end], y) in [(from, source), (to, dest)] {
+                    svg.push(
+                        svg::rect(start, y, (end - start).max(1.0), SVG_BAR)
+                            .fill(color)
+                            .title(&title),
+                    );
+                }
+                svg.push(
+                    svg::arrow(
+                        (from[0] + from[1]) / 2.0,
+                        source + SVG_BAR + 2.0,
+                        (to[0] + to[1]) / 2.0,
+                        dest - 2.0,
+                    )
+                    .stroke(color, 1.5)
+                    .title(title),
+                );
+            }
+            for trace in &traces {
+                for (value, y) in [(trace[i], source), (trace[i + 1], dest)] {
+                    svg.push(
+                        svg::line(x(value), y - 2.0, x(value), y + SVG_BAR + 2.0)
+                            .stroke(Color::WHITE, 1.0)
+                            .title(format!("seed {}: {value}", trace[0])),
+                    );
+                }
+            }
+        }
+        svg
+    }
+}
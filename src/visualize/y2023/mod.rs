@@ -0,0 +1,6 @@
+//! The visualizations for 2023.
+
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
@@ -0,0 +1,50 @@
+//! Checks parsing the answers and the submission history, malformed lines included.
+
+use aoc::answers::{Answers, Submission, Verdict};
+
+#[test]
+fn answers() {
+    let answers = Answers::parse("1 1 142\n1 2 281\n\n 5 1 35 \n");
+    assert_eq!(answers.get(1, 1), Some(142));
+    assert_eq!(answers.get(1, 2), Some(281));
+    assert_eq!(answers.get(5, 1), Some(35));
+    assert_eq!(answers.get(5, 2), None);
+    assert_eq!(Answers::parse(""), Answers::default());
+}
+
+#[test]
+fn malformed_answers() {
+    let answers = Answers::parse("1 1 142\n1 2\n2 1 8 9\n2 2 -1\nthree 1 2\n4 1 13");
+    assert_eq!(
+        answers.0.into_iter().collect::<Vec<_>>(),
+        [((1, 1), 142), ((4, 1), 13)]
+    );
+}
+
+#[test]
+fn submissions() {
+    assert_eq!(
+        "1701388800 1 2 281 too-low".parse(),
+        Ok(Submission {
+            time: 1701388800,
+            day: 1,
+            part: 2,
+            answer: 281,
+            verdict: Verdict::TooLow,
+        })
+    );
+    for verdict in ["correct", "too-high", "too-low", "wrong"] {
+        let submission = format!("0 1 1 2 {verdict}").parse::<Submission>().unwrap();
+        assert_eq!(submission.verdict.to_string(), verdict);
+    }
+    for line in [
+        "",
+        "1701388800 1 2 281",
+        "1701388800 1 2 281 correct extra",
+        "1701388800 1 2 281 right",
+        "1701388800 1 2 -281 correct",
+        "yesterday 1 2 281 correct",
+    ] {
+        assert_eq!(line.parse::<Submission>(), Err(()), "{line:?}");
+    }
+}
@@ -0,0 +1,138 @@
+//! Checks the C API, from Rust and through a small C program linked against the shared
+//! library.
+#![cfg(feature = "capi")]
+
+use std::{
+    env, fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+    ptr,
+};
+
+use aoc::{
+    capi::{aoc_solve, AocStatus, AOC_ANSWER_LEN},
+    input::y2023::day_04::TEST_INPUT,
+    snapshot::{diff, UPDATE_ENV},
+};
+
+fn solve(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: &[u8],
+    out_len: usize,
+) -> Result<String, AocStatus> {
+    let mut out = vec![0xff_u8; out_len];
+    let status = unsafe {
+        aoc_solve(
+            year,
+            day,
+            part,
+            input.as_ptr(),
+            input.len(),
+            out.as_mut_ptr().cast(),
+            out.len(),
+        )
+    };
+    if status != AocStatus::Ok {
+        return Err(status);
+    }
+    let end = out.iter().position(|&b| b == 0).unwrap();
+    Ok(String::from_utf8(out[..end].to_vec()).unwrap())
+}
+
+#[test]
+fn statuses() {
+    let input = TEST_INPUT.as_bytes();
+    assert_eq!(
+        solve(2023, 4, 1, input, AOC_ANSWER_LEN).as_deref(),
+        Ok("13")
+    );
+    assert_eq!(
+        solve(2023, 4, 2, input, AOC_ANSWER_LEN).as_deref(),
+        Ok("30")
+    );
+    // the answer and its NUL need exactly 3 bytes
+    assert_eq!(solve(2023, 4, 1, input, 3).as_deref(), Ok("13"));
+    assert_eq!(solve(2023, 4, 1, input, 2), Err(AocStatus::BufferTooSmall));
+    assert_eq!(
+        solve(2023, 25, 1, input, AOC_ANSWER_LEN),
+        Err(AocStatus::Unimplemented)
+    );
+    assert_eq!(
+        solve(2023, 4, 3, input, AOC_ANSWER_LEN),
+        Err(AocStatus::Unimplemented)
+    );
+    assert_eq!(
+        solve(2023, 4, 1, b"Card 1: \xff", AOC_ANSWER_LEN),
+        Err(AocStatus::InvalidUtf8)
+    );
+    assert_eq!(
+        solve(2023, 4, 1, b"Card one", AOC_ANSWER_LEN),
+        Err(AocStatus::InvalidInput)
+    );
+    let status = unsafe { aoc_solve(2023, 4, 1, ptr::null(), 5, ptr::null_mut(), 0) };
+    assert_eq!(status, AocStatus::NullPointer);
+}
+
+#[test]
+fn header() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/aoc.h"));
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+    if env::var_os(UPDATE_ENV).is_some() {
+        fs::write(&path, generated).unwrap();
+        return;
+    }
+    let committed = fs::read_to_string(&path).unwrap();
+    assert!(
+        committed == generated,
+        "include/aoc.h is out of date (rerun with {UPDATE_ENV}=1 to update it):\n{}",
+        diff(&committed, generated)
+    );
+}
+
+#[test]
+fn c_harness() {
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // the test binary lives in the same `deps` directory as the shared library
+    let lib_dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let exe = env::temp_dir().join(format!("aoc_capi_harness_{}", std::process::id()));
+    let status = Command::new(&cc)
+        .arg(root.join("tests/capi/harness.c"))
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-laoc")
+        .arg("-o")
+        .arg(&exe)
+        .status();
+    let Ok(status) = status else {
+        eprintln!("no C compiler ({cc}), skipping");
+        return;
+    };
+    assert!(status.success(), "compiling the harness failed");
+
+    // cargo puts `target/debug` (with a possibly stale libaoc) on the library path,
+    // which would take precedence over the rpath
+    let mut child = Command::new(&exe)
+        .args(["2023", "4", "2"])
+        .env_remove("LD_LIBRARY_PATH")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(TEST_INPUT.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let _ = std::fs::remove_file(&exe);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "30\n");
+}
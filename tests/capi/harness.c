@@ -0,0 +1,24 @@
+/* Solves a part on stdin through the C API: `harness YEAR DAY PART < INPUT` */
+
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "aoc.h"
+
+int main(int argc, char **argv) {
+    if (argc != 4) {
+        fprintf(stderr, "usage: harness YEAR DAY PART < INPUT\n");
+        return 2;
+    }
+    char input[1 << 16];
+    size_t len = fread(input, 1, sizeof input, stdin);
+    char answer[AOC_ANSWER_LEN];
+    AocStatus status = aoc_solve(atoi(argv[1]), atoi(argv[2]), atoi(argv[3]),
+                                 (const uint8_t *)input, len, answer, sizeof answer);
+    if (status != AOC_STATUS_OK) {
+        fprintf(stderr, "%s\n", aoc_status_message(status));
+        return 1;
+    }
+    printf("%s\n", answer);
+    return 0;
+}
@@ -0,0 +1,56 @@
+//! Checks how the binaries take their input.
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::input::y2023::day_04::TEST_INPUT;
+
+#[test]
+fn stdin_only_with_dash() {
+    // stdin stays open without anything coming, like in a script or an editor
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "1", "--year", "2023", "--profile", "nobody"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let start = Instant::now();
+    while child.try_wait().unwrap().is_none() {
+        if start.elapsed() > Duration::from_secs(30) {
+            child.kill().unwrap();
+            panic!("waiting for stdin instead of using the stored input");
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("[nobody]: can't load input"), "{stdout}");
+}
+
+#[test]
+fn dash() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "4", "--year", "2023", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(TEST_INPUT.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("part 1 [stdin]: 13") && stdout.contains("part 2 [stdin]: 30"),
+        "{stdout}"
+    );
+}
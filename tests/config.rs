@@ -0,0 +1,93 @@
+//! Checks parsing and merging of the config files.
+
+use std::{path::Path, time::Duration};
+
+use aoc::{
+    config::{self, Config, Format},
+    crypt,
+};
+
+#[test]
+fn defaults() {
+    let config = Config::parse("").unwrap();
+    assert_eq!(config, Config::default());
+    assert_eq!(config.format(), Format::Text);
+    assert_eq!(config.timeout(), Duration::from_secs(60));
+    assert_eq!(config.bench_iterations(), 1000);
+    assert_eq!(config.verify_seeds(), 20);
+    assert!(config.inputs_dir().ends_with("inputs"));
+    assert!(config.plugins_dir().ends_with("plugins"));
+    assert!(config.session_file().ends_with(".aoc-key"));
+}
+
+#[test]
+fn parse() {
+    let config = Config::parse(
+        r#"
+        year = 2022
+        format = "csv"
+        timeout = 2.5
+
+        [paths]
+        inputs = "/somewhere/else"
+        session = "secrets/key"
+
+        [bench]
+        iterations = 10
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.year, Some(2022));
+    assert_eq!(config.format(), Format::Csv);
+    assert_eq!(config.timeout(), Duration::from_millis(2500));
+    assert_eq!(config.inputs_dir(), Path::new("/somewhere/else"));
+    assert!(config.puzzles_dir().ends_with("puzzles"));
+    assert_eq!(config.session_file(), config::root().join("secrets/key"));
+    assert_eq!(config.bench_iterations(), 10);
+    assert_eq!(config.bench_time(), Duration::from_secs(1));
+}
+
+#[test]
+fn merge() {
+    let project = Config::parse("year = 2023\ntimeout = 10\n[verify]\nseeds = 5").unwrap();
+    let local = Config::parse("timeout = 1\n[bench]\ntime = 0.5").unwrap();
+    let config = project.merge(local);
+    assert_eq!(config.year, Some(2023));
+    assert_eq!(config.timeout(), Duration::from_secs(1));
+    assert_eq!(config.bench_time(), Duration::from_millis(500));
+    assert_eq!(config.verify_seeds(), 5);
+}
+
+#[test]
+fn unknown_keys() {
+    let err = Config::parse("yaer = 2023").unwrap_err().to_string();
+    assert!(err.contains("yaer"), "{err}");
+    let err = Config::parse("[bench]\nruns = 3").unwrap_err().to_string();
+    assert!(err.contains("runs") && err.contains("iterations"), "{err}");
+    assert!(Config::parse("format = \"xml\"").is_err());
+}
+
+#[test]
+fn invalid_durations() {
+    for value in ["-1", "nan", "inf", "-inf", "1e30"] {
+        for config in [
+            format!("timeout = {value}"),
+            format!("[bench]\ntime = {value}"),
+        ] {
+            let err = Config::parse(&config).unwrap_err().to_string();
+            assert!(err.contains("invalid duration"), "{config}: {err}");
+        }
+    }
+    let config = Config::parse("timeout = 0\n[bench]\ntime = 0.0").unwrap();
+    assert_eq!(config.timeout(), Duration::ZERO);
+    assert_eq!(config.bench_time(), Duration::ZERO);
+    assert!(config::valid_seconds(0.5));
+    assert!(!config::valid_seconds(-0.5));
+}
+
+#[test]
+fn project_config() {
+    Config::load().unwrap();
+    // the input key is read from wherever the config puts it
+    assert_eq!(crypt::key_file(), Config::get().session_file());
+}
@@ -0,0 +1,85 @@
+//! Checks the input encryption: round trips, wrong keys, tampering and key parsing.
+
+use aoc::crypt::{self, Error, Key};
+
+const HEX: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+#[test]
+fn round_trip() {
+    let key = Key::generate();
+    for plaintext in [&b""[..], b"1abc2\npqr3stu8vwx", &[0xff; 10_000]] {
+        let encrypted = crypt::encrypt(&key, plaintext);
+        assert!(encrypted.starts_with(b"AOC1"));
+        assert_eq!(crypt::decrypt(&key, &encrypted).unwrap(), plaintext);
+    }
+    // every encryption gets a fresh nonce
+    assert_ne!(
+        crypt::encrypt(&key, b"input"),
+        crypt::encrypt(&key, b"input")
+    );
+}
+
+#[test]
+fn wrong_key() {
+    let encrypted = crypt::encrypt(&Key::generate(), b"input");
+    assert!(matches!(
+        crypt::decrypt(&Key::generate(), &encrypted),
+        Err(Error::Decryption)
+    ));
+}
+
+#[test]
+fn tampered() {
+    let key = Key::generate();
+    let encrypted = crypt::encrypt(&key, b"input");
+    // flipping a bit anywhere after the magic bytes breaks the authentication
+    for idx in 4..encrypted.len() {
+        let mut tampered = encrypted.clone();
+        tampered[idx] ^= 1;
+        assert!(
+            matches!(crypt::decrypt(&key, &tampered), Err(Error::Decryption)),
+            "byte {idx}"
+        );
+    }
+    let truncated = &encrypted[..encrypted.len() - 1];
+    assert!(matches!(
+        crypt::decrypt(&key, truncated),
+        Err(Error::Decryption)
+    ));
+    assert!(matches!(
+        crypt::decrypt(&key, &encrypted[..10]),
+        Err(Error::Corrupted)
+    ));
+    assert!(matches!(
+        crypt::decrypt(&key, b"1abc2\npqr3stu8vwx"),
+        Err(Error::Corrupted)
+    ));
+}
+
+#[test]
+fn from_hex() {
+    let key = Key::from_hex(HEX).unwrap();
+    assert_eq!(key.to_hex(), HEX);
+    assert_eq!(Key::from_hex(&format!(" {HEX}\n")).unwrap(), key);
+    assert_eq!(Key::from_hex(&HEX.to_uppercase()).unwrap(), key);
+    let generated = Key::generate();
+    assert_eq!(Key::from_hex(&generated.to_hex()).unwrap(), generated);
+
+    for invalid in [
+        "",
+        &HEX[..62],
+        &format!("{HEX}00"),
+        // signs would get through `from_str_radix`
+        &format!("+f{}", &HEX[2..]),
+        &format!("{}-1", &HEX[..62]),
+        &format!("{}zz", &HEX[..62]),
+        &format!("{}é", &HEX[..62]),
+    ] {
+        assert!(
+            matches!(Key::from_hex(invalid), Err(Error::InvalidKey)),
+            "{invalid:?}"
+        );
+    }
+    // the key stays out of debug output
+    assert_eq!(format!("{key:?}"), "Key(..)");
+}
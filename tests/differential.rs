@@ -0,0 +1,122 @@
+//! Checks the solvers against the brute-force oracles on small generated inputs.
+
+use aoc::{differential, generate, rng::Rng};
+
+const SEEDS: u64 = 100;
+
+fn check(year: usize, day: usize, generate: impl Fn(&mut Rng) -> String) {
+    let mismatches = (0..SEEDS)
+        .flat_map(|seed| {
+            let input = generate(&mut Rng::seed_from(seed));
+            differential::compare(year, day, &input)
+                .into_iter()
+                .map(move |mismatch| format!("seed {seed}: {mismatch}"))
+        })
+        .collect::<Vec<_>>();
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[test]
+fn y2023_day_01() {
+    let params = generate::y2023::day_01::Params {
+        lines: 50,
+        ..Default::default()
+    };
+    check(2023, 1, |rng| {
+        generate::y2023::day_01::generate(rng, &params)
+    });
+}
+
+#[test]
+fn y2023_day_02() {
+    let params = generate::y2023::day_02::Params {
+        games: 20,
+        ..Default::default()
+    };
+    check(2023, 2, |rng| {
+        generate::y2023::day_02::generate(rng, &params)
+    });
+}
+
+#[test]
+fn y2023_day_03() {
+    let params = generate::y2023::day_03::Params {
+        width: 30,
+        height: 30,
+        ..Default::default()
+    };
+    check(2023, 3, |rng| {
+        generate::y2023::day_03::generate(rng, &params)
+    });
+}
+
+#[test]
+fn y2023_day_04() {
+    let params = generate::y2023::day_04::Params {
+        cards: 20,
+        ..Default::default()
+    };
+    check(2023, 4, |rng| {
+        generate::y2023::day_04::generate(rng, &params)
+    });
+}
+
+#[test]
+fn y2023_day_05() {
+    let params = generate::y2023::day_05::Params {
+        max_value: 200,
+        ..Default::default()
+    };
+    check(2023, 5, |rng| {
+        generate::y2023::day_05::generate(rng, &params)
+    });
+}
+
+#[test]
+fn examples() {
+    for (year, day, input) in [
+        (2023, 3, aoc::input::y2023::day_03::TEST_INPUT),
+        (2023, 4, aoc::input::y2023::day_04::TEST_INPUT),
+        (2023, 5, aoc::input::y2023::day_05::TEST_INPUT),
+    ] {
+        let mismatches = differential::compare(year, day, input);
+        assert!(mismatches.is_empty(), "{mismatches:?}");
+    }
+}
+
+/// Composing day 5 maps gives the same destinations as applying them one after another
+#[test]
+fn y2023_day_05_compose() {
+    use aoc::y2023::day_05::{Map, Range};
+
+    let map = |rng: &mut Rng| {
+        Map((0..rng.range_inclusive(0..=4))
+            .map(|_| Range {
+                dest_start: rng.below(50),
+                source_start: rng.below(50),
+                len: rng.below(20),
+            })
+            .collect())
+    };
+    for seed in 0..SEEDS {
+        let rng = &mut Rng::seed_from(seed);
+        let (first, second) = (map(rng), map(rng));
+        // everything the maps touch, with the identity around it
+        let identity = Map(vec![Range {
+            dest_start: 0,
+            source_start: 0,
+            len: 100,
+        }]);
+        let composed = second.compose(&first.compose(&identity));
+        for x in 0..100 {
+            assert_eq!(
+                composed.dest_for(x),
+                second.dest_for(first.dest_for(x)),
+                "seed {seed}: {x}"
+            );
+        }
+        // the pieces don't overlap, so the order they're in doesn't matter
+        let covered = composed.0.iter().map(|range| range.len).sum::<usize>();
+        assert_eq!(covered, 100, "seed {seed}");
+    }
+}
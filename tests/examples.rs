@@ -0,0 +1,26 @@
+//! Checks the solutions against the examples from the puzzle descriptions.
+
+use aoc::{days, puzzle::Examples};
+
+#[test]
+fn examples() {
+    let mut failures = vec![];
+    for solutions in &days::DAYS {
+        let (year, day) = (solutions.year, solutions.day);
+        for (i, example) in Examples::load(year, day).0.iter().enumerate() {
+            let Some(solver) = solutions.solvers[example.part - 1] else {
+                continue;
+            };
+            let answer = solver(&example.input);
+            if answer != example.answer {
+                failures.push(format!(
+                    "{year} day {day} example {} (part {}): got {answer}, expected {}",
+                    i + 1,
+                    example.part,
+                    example.answer
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
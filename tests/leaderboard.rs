@@ -0,0 +1,118 @@
+//! Checks the leaderboard analytics against a small hand-made export.
+
+use aoc::leaderboard::{csv_field, Leaderboard, Scoring};
+use serde_json::Value;
+
+const FIXTURE: &str = include_str!("fixtures/leaderboard.json");
+
+fn fixture() -> Leaderboard {
+    FIXTURE.parse().unwrap()
+}
+
+fn ranking(scoring: Scoring) -> Vec<(u64, u64)> {
+    fixture()
+        .rankings(scoring)
+        .into_iter()
+        .map(|(member, score)| (member.id, score))
+        .collect()
+}
+
+/// Parse the fixture after making a change to it
+fn modified(change: impl FnOnce(&mut Value)) -> Result<Leaderboard, serde_json::Error> {
+    let mut export = serde_json::from_str::<Value>(FIXTURE).unwrap();
+    change(&mut export);
+    export.to_string().parse()
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(modified(|_| {}).unwrap(), fixture());
+    for (day, expected) in [
+        ("two", "expected key to be a number"),
+        ("-2", "expected usize"),
+        ("0", "invalid day 0"),
+        ("26", "invalid day 26"),
+    ] {
+        let err = modified(|export| {
+            let days = &mut export["members"]["1"]["completion_day_level"];
+            let stars = days.as_object_mut().unwrap().remove("2").unwrap();
+            days[day] = stars;
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains(expected), "{day}: {err}");
+    }
+    let err = modified(|export| {
+        let parts = &mut export["members"]["1"]["completion_day_level"]["1"];
+        let star = parts.as_object_mut().unwrap().remove("2").unwrap();
+        parts["3"] = star;
+    })
+    .unwrap_err();
+    assert!(err.to_string().contains("invalid part 3 of day 1"), "{err}");
+    let err = modified(|export| export["event"] = "Advent of Code".into()).unwrap_err();
+    assert!(err.to_string().contains("expected a year"), "{err}");
+}
+
+#[test]
+fn unlock() {
+    let leaderboard = fixture();
+    assert_eq!(leaderboard.unlock(1), 1701406800);
+    assert_eq!(leaderboard.unlock(2), 1701493200);
+}
+
+#[test]
+fn local_matches_export() {
+    let leaderboard = fixture();
+    for (member, score) in leaderboard.rankings(Scoring::Local) {
+        assert_eq!(score, member.local_score, "{}", member.display_name());
+    }
+    assert_eq!(ranking(Scoring::Local), [(1, 11), (2, 7), (3, 2)]);
+}
+
+#[test]
+fn alternative_scorings() {
+    assert_eq!(ranking(Scoring::Stars), [(1, 4), (2, 3), (3, 2)]);
+    assert_eq!(ranking(Scoring::Time), [(1, 8200), (2, 8600), (3, 72060)]);
+    assert_eq!(ranking(Scoring::Delta), [(1, 5), (3, 3), (2, 1)]);
+}
+
+#[test]
+fn times() {
+    let leaderboard = fixture();
+    let members = leaderboard.members().collect::<Vec<_>>();
+    assert_eq!(leaderboard.completion_time(members[1], 2, 1), Some(7200));
+    assert_eq!(leaderboard.completion_time(members[1], 2, 2), None);
+    assert_eq!(leaderboard.delta(members[2], 1), Some(60));
+    assert_eq!(
+        leaderboard.times_table().to_string(),
+        "\
+day  name                   part 1    part 2    delta
+  1  bob, the builder      0:03:20   0:20:00  0:16:40
+  1  alice                 0:05:00   0:10:00  0:05:00
+  1  (anonymous user #3)  10:00:00  10:01:00  0:01:00
+  2  alice                 1:00:00   1:01:40  0:01:40
+  2  bob, the builder      2:00:00         -        -
+"
+    );
+}
+
+#[test]
+fn csv() {
+    assert_eq!(
+        fixture().rankings_table(Scoring::Time).to_csv(),
+        "\
+rank,name,total time,stars
+1,alice,2:16:40,4
+2,\"bob, the builder\",2:23:20,3
+3,(anonymous user #3),20:01:00,2
+"
+    );
+}
+
+#[test]
+fn csv_fields() {
+    assert_eq!(csv_field("alice"), "alice");
+    assert_eq!(csv_field(""), "");
+    assert_eq!(csv_field("bob, the builder"), "\"bob, the builder\"");
+    assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+}
@@ -0,0 +1,122 @@
+//! Round trips through the input compressor and corrupted data.
+
+use std::collections::HashSet;
+
+use aoc::lz::{compress, decompress};
+
+/// Bytes without a repeated 3 byte sequence, which leaves nothing to compress
+/// (xorshift, skipping bytes that would repeat one)
+fn noise(len: usize, mut state: u64) -> Vec<u8> {
+    let mut seen = HashSet::new();
+    let mut data = Vec::with_capacity(len);
+    while data.len() < len {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let byte = state as u8;
+        if let [.., a, b] = data[..] {
+            if !seen.insert([a, b, byte]) {
+                continue;
+            }
+        }
+        data.push(byte);
+    }
+    data
+}
+
+fn round_trip(data: &[u8]) -> Vec<u8> {
+    let compressed = compress(data);
+    assert_eq!(decompress(&compressed).as_deref(), Some(data));
+    compressed
+}
+
+#[test]
+fn empty() {
+    assert_eq!(round_trip(b""), [0, 0, 0, 0]);
+}
+
+#[test]
+fn literals() {
+    round_trip(b"a");
+    round_trip(b"ab");
+    round_trip(b"1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet");
+    let data = noise(10_000, 1);
+    // flag bytes are the only overhead
+    assert_eq!(
+        round_trip(&data).len(),
+        4 + 10_000 + 10_000_usize.div_ceil(8)
+    );
+}
+
+#[test]
+fn runs() {
+    for len in [3, 4, 17, 18, 19, 100, 100_000] {
+        let data = vec![b'.'; len];
+        let compressed = round_trip(&data);
+        if len > 100 {
+            // a literal then back references of the longest length, at 9 per byte
+            assert!(compressed.len() < len / 8, "{len}");
+        }
+    }
+}
+
+#[test]
+fn overlapping() {
+    // matches one and two bytes back copy what they produce
+    let compressed = round_trip(b"abababababababababab");
+    assert!(compressed.len() < 10);
+    let data = b"467..114..\n...*......\n..35..633.\n".repeat(200);
+    assert!(round_trip(&data).len() < data.len() / 8);
+}
+
+#[test]
+fn window() {
+    for distance in [4094, 4095, 4096, 4097, 4098, 5000] {
+        let mut data = noise(distance, 3);
+        // the longest match repeats from the start
+        data.extend_from_within(..18);
+        let compressed = round_trip(&data);
+        // the repeat is a single back reference as long as it's in the window
+        let literals = 4 + data.len() + data.len().div_ceil(8);
+        if distance <= 4096 {
+            assert!(compressed.len() < literals - 10, "{distance}");
+        } else {
+            // only the 3 byte sequences where the copy starts can match
+            assert!(compressed.len() >= literals - 2, "{distance}");
+        }
+    }
+    let data = noise(50_000, 4).repeat(2);
+    round_trip(&data);
+}
+
+#[test]
+fn corrupted() {
+    let data = b"1abc2\npqr3stu8vwx\n".repeat(10);
+    let compressed = compress(&data);
+    for len in 0..compressed.len() {
+        assert_eq!(decompress(&compressed[..len]), None, "truncated to {len}");
+    }
+    let mut trailing = compressed.clone();
+    trailing.push(0);
+    assert_eq!(decompress(&trailing), None);
+    // a longer declared length runs out of data, a shorter one leaves some over
+    for len in [data.len() + 1, data.len() - 1, u32::MAX as usize] {
+        let mut wrong = compressed.clone();
+        wrong[..4].copy_from_slice(&(len as u32).to_le_bytes());
+        assert_eq!(decompress(&wrong), None, "length {len}");
+    }
+    // a back reference before the start of the data
+    assert_eq!(decompress(&[3, 0, 0, 0, 1, 0x10, 0]), None);
+    // a back reference past the end of the data
+    assert_eq!(decompress(&[4, 0, 0, 0, 2, b'a', 0x01, 0]), None);
+    // anything else decompresses to something or nothing, but doesn't panic
+    for idx in 4..compressed.len() {
+        for bit in 0..8 {
+            let mut flipped = compressed.clone();
+            flipped[idx] ^= 1 << bit;
+            if let Some(out) = decompress(&flipped) {
+                assert_eq!(out.len(), data.len());
+            }
+        }
+    }
+}
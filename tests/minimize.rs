@@ -0,0 +1,61 @@
+//! Checks the delta debugging on predicates with known minimal failing inputs.
+
+use aoc::minimize;
+
+/// Whether no single item can be removed from a failing subset without it passing
+fn one_minimal<T: Clone>(items: &[T], failing: impl Fn(&[T]) -> bool) -> bool {
+    failing(items)
+        && (0..items.len()).all(|i| {
+            let mut without = items.to_vec();
+            without.remove(i);
+            !failing(&without)
+        })
+}
+
+#[test]
+fn ddmin() {
+    // failing whenever a few specific items are in there
+    let contains = |items: &[usize]| [3, 7, 11].iter().all(|item| items.contains(item));
+    let min = minimize::ddmin((0..50).collect(), contains);
+    assert_eq!(min, [3, 7, 11]);
+    assert!(one_minimal(&min, contains));
+
+    // failing with enough of some kind of item, which has many minimal subsets
+    let evens = |items: &[usize]| items.iter().filter(|item| *item % 2 == 0).count() >= 4;
+    let min = minimize::ddmin((0..100).collect(), evens);
+    assert_eq!(min.len(), 4);
+    assert!(one_minimal(&min, evens));
+
+    // failing only on adjacent items, which splitting into chunks keeps cutting apart
+    let pair = |items: &[usize]| items.windows(2).any(|w| w == [31, 32]);
+    let min = minimize::ddmin((0..64).collect(), pair);
+    assert_eq!(min, [31, 32]);
+
+    assert_eq!(minimize::ddmin(vec![1], |_: &[i32]| true), [1]);
+    assert_eq!(minimize::ddmin(vec![1, 2], |_: &[i32]| true), [1]);
+}
+
+#[test]
+fn tokens() {
+    assert_eq!(
+        minimize::tokens("Game 12: 3 blue"),
+        ["Game", " ", "12", ":", " ", "3", " ", "blue"]
+    );
+    assert_eq!(minimize::tokens("..*é9"), [".", ".", "*", "é", "9"]);
+    assert!(minimize::tokens("").is_empty());
+}
+
+#[test]
+fn minimize() {
+    let input = "seeds: 1 2\n\nfirst map:\n1 2 3\n4 bad 6\n\nsecond map:\n7 8 9";
+    let min = minimize::minimize(input, |input| input.contains("bad"));
+    assert_eq!(min, "bad");
+
+    // tokens of a line get dropped until just what matters is left
+    let min = minimize::minimize(input, |input| {
+        input
+            .lines()
+            .any(|line| line.contains('4') && line.contains('6'))
+    });
+    assert_eq!(min, "46");
+}
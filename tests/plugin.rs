@@ -0,0 +1,110 @@
+//! Loads the example plugin (`examples/plugin.rs`) and checks its variants.
+
+use std::{env, path::PathBuf};
+
+use aoc::{
+    answers::Answers,
+    check::{self, Action, Status},
+    config::Config,
+    differential::{self, Outcome},
+    generate,
+    plugin::{Error, Plugin, Plugins},
+    puzzle::Examples,
+    rng::Rng,
+};
+
+/// The directory `cargo test` put the test binary in (`deps`)
+fn deps_dir() -> PathBuf {
+    env::current_exe().unwrap().parent().unwrap().to_path_buf()
+}
+
+fn library(dir: PathBuf, name: &str) -> PathBuf {
+    dir.join(format!(
+        "{}{name}{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    ))
+}
+
+fn example_plugin() -> Plugin {
+    // `cargo test` builds the examples next to `deps`, but not when only running some
+    // of the tests
+    let path = library(deps_dir().parent().unwrap().join("examples"), "plugin");
+    unsafe { Plugin::load(&path) }.unwrap_or_else(|err| {
+        panic!(
+            "{}: {err} (try `cargo build --example plugin` first)",
+            path.display()
+        )
+    })
+}
+
+#[test]
+fn declaration() {
+    let plugin = example_plugin();
+    assert_eq!(plugin.name, "example");
+    let parts = plugin
+        .variants
+        .iter()
+        .map(|variant| (variant.year, variant.day, variant.part))
+        .collect::<Vec<_>>();
+    assert_eq!(parts, [(2023, 1, 1), (2023, 1, 2), (2023, 4, 1)]);
+}
+
+#[test]
+fn variants() {
+    for variant in example_plugin().variants {
+        let (year, day, part) = (variant.year, variant.day, variant.part);
+        for example in Examples::load(year, day).0 {
+            if example.part == part {
+                assert_eq!(variant.run(&example.input), Outcome::Answer(example.answer));
+            }
+        }
+        let solver = aoc::days::get(year, day).unwrap().solvers[part - 1].unwrap();
+        for seed in 0..20 {
+            let input = generate::generate(year, day, &mut Rng::seed_from(seed)).unwrap();
+            assert_eq!(
+                variant.run(&input),
+                differential::run(solver, &input),
+                "{year} day {day} part {part} on seed {seed}"
+            );
+        }
+    }
+}
+
+#[test]
+fn failures() {
+    let plugin = example_plugin();
+    let variant = &plugin.variants[0];
+    assert!(matches!(variant.run("no digits"), Outcome::Panic(_)));
+
+    let report = check::variant(
+        variant,
+        "1abc2",
+        &Answers::default(),
+        Some(13),
+        Action::Run,
+        &Config::default(),
+    );
+    assert_eq!(
+        report.status,
+        Status::Wrong {
+            answer: 12,
+            expected: 13
+        }
+    );
+}
+
+#[test]
+fn not_a_plugin() {
+    // our own shared library doesn't export the plugin functions
+    let result = unsafe { Plugin::load(&library(deps_dir(), "aoc")) };
+    assert!(matches!(result, Err(Error::NotAPlugin)), "{result:?}");
+    assert!(unsafe { Plugin::load(&library(deps_dir(), "missing")) }.is_err());
+}
+
+#[test]
+fn missing_dir() {
+    let plugins = unsafe { Plugins::load(&deps_dir().join("no plugins here")) };
+    assert!(plugins.plugins.is_empty());
+    assert!(plugins.errors.is_empty());
+}
@@ -0,0 +1,107 @@
+//! Checks the `const fn` solutions against the runtime ones, and the answers computed at
+//! compile time against solving the embedded inputs at runtime.
+
+use aoc::{
+    differential::{self, Outcome},
+    generate,
+    puzzle::Examples,
+    rng::Rng,
+    y2023::{day_01, day_04},
+};
+
+const SEEDS: u64 = 50;
+
+type Solver = fn(&str) -> usize;
+
+/// The runtime and `const fn` solvers of both parts of a day
+fn check(day: usize, solvers: [(Solver, Solver); 2]) {
+    let examples = Examples::load(2023, day)
+        .0
+        .into_iter()
+        .map(|example| (Some(example.part), example.input));
+    let generated = (0..SEEDS).map(|seed| {
+        let input = generate::generate(2023, day, &mut Rng::seed_from(seed)).unwrap();
+        (None, input)
+    });
+    for (part, input) in examples.chain(generated) {
+        for (i, (runtime, constant)) in solvers.iter().enumerate() {
+            if part.is_some_and(|part| part != i + 1) {
+                continue;
+            }
+            // big generated inputs can overflow, which both versions have to notice
+            let (constant, runtime) = (
+                differential::run(*constant, &input),
+                differential::run(*runtime, &input),
+            );
+            let agree = match (&constant, &runtime) {
+                (Outcome::Answer(constant), Outcome::Answer(runtime)) => constant == runtime,
+                (Outcome::Panic(_), Outcome::Panic(_)) => true,
+                _ => false,
+            };
+            assert!(
+                agree,
+                "2023 day {day} part {}: {constant} vs {runtime} on\n{input}",
+                i + 1
+            );
+        }
+    }
+}
+
+#[test]
+fn y2023_day_01() {
+    check(
+        1,
+        [
+            (day_01::fst, day_01::fst_const),
+            (day_01::snd, day_01::snd_const),
+        ],
+    );
+}
+
+#[test]
+fn y2023_day_04() {
+    check(
+        4,
+        [
+            (day_04::fst, day_04::fst_const),
+            (day_04::snd, day_04::snd_const),
+        ],
+    );
+}
+
+#[cfg(feature = "embed")]
+#[test]
+fn precomputed() {
+    use aoc::{input, precomputed::y2023, profile::Profile};
+
+    let days = [
+        (
+            1,
+            [y2023::day_01::FST, y2023::day_01::SND],
+            [day_01::fst, day_01::snd] as [Solver; 2],
+        ),
+        (
+            4,
+            [y2023::day_04::FST, y2023::day_04::SND],
+            [day_04::fst, day_04::snd],
+        ),
+    ];
+    for (day, answers, solvers) in days {
+        let Some(input) = input::embedded_plain(2023, day) else {
+            assert_eq!(answers, [None, None]);
+            assert!(
+                input::try_load(&Profile::current(), 2023, day).is_err(),
+                "2023 day {day} has an input but no plain text, is it in `PRECOMPUTED` in build.rs?"
+            );
+            continue;
+        };
+        for (i, (answer, solver)) in answers.iter().zip(solvers).enumerate() {
+            assert_eq!(
+                *answer,
+                Some(solver(input)),
+                "2023 day {day} part {}",
+                i + 1
+            );
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Checks profile names and finding the profiles.
+
+use std::{env, fs};
+
+use aoc::profile::{Profile, DEFAULT_PROFILE, PROFILE_ENV};
+
+#[test]
+fn names() {
+    for name in ["default", "alice", "bob-2", "c_d", "X"] {
+        assert_eq!(name.parse::<Profile>().unwrap().name(), name);
+    }
+    for name in ["", ".", "..", ".cache", "a/b", "a b", "ä", "alice\n"] {
+        assert_eq!(name.parse::<Profile>(), Err(()), "{name:?}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid profile name \"../etc\"")]
+fn new_invalid() {
+    Profile::new("../etc");
+}
+
+#[test]
+fn current() {
+    // the only test touching the environment, so there's nothing to race with
+    env::remove_var(PROFILE_ENV);
+    assert_eq!(Profile::current(), Profile::new(DEFAULT_PROFILE));
+    env::set_var(PROFILE_ENV, "alice");
+    assert_eq!(Profile::current(), Profile::new("alice"));
+    env::remove_var(PROFILE_ENV);
+}
+
+#[test]
+fn in_dir() {
+    let dir = env::temp_dir().join(format!("aoc-profiles-{}", std::process::id()));
+    for name in ["bob", "alice", ".cache", "not a profile"] {
+        fs::create_dir_all(dir.join(name).join("2023")).unwrap();
+    }
+    fs::write(dir.join("carol"), "a file, not a profile").unwrap();
+    let profiles = Profile::in_dir(&dir);
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(profiles, [Profile::new("alice"), Profile::new("bob")]);
+    assert_eq!(Profile::in_dir(&dir), []);
+}
@@ -0,0 +1,137 @@
+//! Checks parsing the progress file, taking submissions into account and rendering it.
+
+use std::time::Duration;
+
+use aoc::{
+    answers::{Submission, Verdict},
+    check,
+    progress::{self, Entry, Progress},
+};
+
+#[test]
+fn parse() {
+    let progress = Progress::parse("1 1 100 160 200\n1 2 200 - -\n\n2 1 - 50 -\n");
+    assert_eq!(
+        progress.get(1, 1),
+        Entry {
+            started: Some(100),
+            first_submission: Some(160),
+            star: Some(200),
+        }
+    );
+    assert_eq!(progress.get(1, 1).solve_time(), Some(100));
+    assert_eq!(progress.get(1, 2).started, Some(200));
+    assert_eq!(progress.get(1, 2).solve_time(), None);
+    assert_eq!(progress.get(2, 1).first_submission, Some(50));
+    assert_eq!(progress.get(3, 1), Entry::default());
+}
+
+#[test]
+fn malformed() {
+    let progress = Progress::parse("1 1 100 160 200\n1 2 200 -\n2 1 x - -\n2 2 - - - -\n3 1 - - 7");
+    assert_eq!(
+        progress.0.keys().copied().collect::<Vec<_>>(),
+        [(1, 1), (3, 1)]
+    );
+}
+
+fn submission(time: u64, day: usize, part: usize, verdict: Verdict) -> Submission {
+    Submission {
+        time,
+        day,
+        part,
+        answer: 0,
+        verdict,
+    }
+}
+
+#[test]
+fn note() {
+    let mut progress = Progress::default();
+    progress.start(1, 1, 100);
+    progress.note(&submission(160, 1, 1, Verdict::TooLow));
+    assert_eq!(progress.get(1, 1).first_submission, Some(160));
+    assert_eq!(progress.get(1, 1).star, None);
+    assert_eq!(progress.get(1, 2), Entry::default());
+
+    // solving part 1 starts part 2
+    progress.note(&submission(200, 1, 1, Verdict::Correct));
+    assert_eq!(
+        progress.get(1, 1),
+        Entry {
+            started: Some(100),
+            first_submission: Some(160),
+            star: Some(200),
+        }
+    );
+    assert_eq!(progress.get(1, 2).started, Some(200));
+
+    // submissions can come in any order (say from the history) and the earliest wins
+    progress.note(&submission(150, 1, 1, Verdict::Correct));
+    assert_eq!(progress.get(1, 1).first_submission, Some(150));
+    assert_eq!(progress.get(1, 1).star, Some(150));
+    assert_eq!(progress.get(1, 2).started, Some(150));
+    progress.note(&submission(300, 1, 1, Verdict::Correct));
+    assert_eq!(progress.get(1, 1).star, Some(150));
+
+    // starting again doesn't move the start
+    progress.start(1, 1, 50);
+    assert_eq!(progress.get(1, 1).started, Some(100));
+    assert_eq!(progress.get(1, 1).solve_time(), Some(50));
+
+    progress.note(&submission(400, 1, 2, Verdict::Correct));
+    assert_eq!(progress.get(1, 2).solve_time(), Some(250));
+    assert_eq!(progress.delta(1), Some(250));
+    assert_eq!(progress.delta(2), None);
+}
+
+#[test]
+fn render() {
+    let mut progress = Progress::default();
+    progress.start(1, 1, 0);
+    progress.note(&submission(42, 1, 1, Verdict::Correct));
+    progress.note(&submission(3_742, 1, 2, Verdict::Correct));
+    // a star without a start time (from before there was a progress file)
+    progress.note(&submission(1_000, 7, 1, Verdict::Correct));
+    progress.note(&submission(1_000, 9, 1, Verdict::Wrong));
+    assert_eq!(
+        progress.render(),
+        "\
+3/50 stars
+
++------+------+------+------+------+
+|  1 **|  2 ..|  3 ..|  4 ..|  5 ..|
++------+------+------+------+------+
+|  6 ..|  7 *.|  8 ..|  9 ..| 10 ..|
++------+------+------+------+------+
+| 11 ..| 12 ..| 13 ..| 14 ..| 15 ..|
++------+------+------+------+------+
+| 16 ..| 17 ..| 18 ..| 19 ..| 20 ..|
++------+------+------+------+------+
+| 21 ..| 22 ..| 23 ..| 24 ..| 25 ..|
++------+------+------+------+------+
+
+day      part 1      part 2       delta
+  1      42.00s     1:01:40     1:01:40
+  7           -           -           -
+"
+    );
+}
+
+#[test]
+fn durations() {
+    for (duration, formatted) in [
+        (Duration::ZERO, "0s"),
+        (Duration::from_nanos(999), "999ns"),
+        (Duration::from_nanos(1_500), "1.5µs"),
+        (Duration::from_micros(2_345), "2.3ms"),
+        (Duration::from_millis(5_678), "5.68s"),
+        (Duration::from_secs(60), "0:01:00"),
+        (Duration::from_secs(36_061), "10:01:01"),
+    ] {
+        assert_eq!(check::format_duration(duration), formatted);
+    }
+    assert_eq!(progress::format_seconds(None), "-");
+    assert_eq!(progress::format_seconds(Some(59)), "59.00s");
+    assert_eq!(progress::format_seconds(Some(3_600)), "1:00:00");
+}
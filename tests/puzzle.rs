@@ -0,0 +1,58 @@
+//! Checks the puzzle page conversion against a small made-up page.
+
+use aoc::puzzle::{Example, Examples, Page};
+
+fn page() -> Page {
+    Page::parse(include_str!("fixtures/puzzle.html"))
+}
+
+#[test]
+fn markdown() {
+    assert_eq!(
+        page().to_markdown(),
+        "\
+## Day 7: Counting Sheep
+
+The elves need you to add up the numbers on each line & sum the results. For example:
+
+```
+1 2 3
+4 5
+```
+
+The lines add up to `6` and `9`, so the total is **`15`**.
+
+What is the *total* of [your input](/2023/day/7/input)?
+
+## Part Two
+
+Now multiply the numbers instead:
+
+- The first line becomes `6`.
+- The second line becomes `20`.
+
+Adding those up gives **`26`**.
+"
+    );
+}
+
+#[test]
+fn examples() {
+    let expected = [
+        Example {
+            part: 1,
+            input: "1 2 3\n4 5".to_string(),
+            answer: 15,
+        },
+        Example {
+            part: 2,
+            input: "1 2 3\n4 5".to_string(),
+            answer: 26,
+        },
+    ];
+    assert_eq!(page().examples(), expected);
+
+    let file = expected.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let parsed: Examples = file.join("\n").parse().unwrap();
+    assert_eq!(parsed.0, expected);
+}
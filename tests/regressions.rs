@@ -0,0 +1,25 @@
+//! Replays the crashing inputs found by the fuzzer (see `src/bin/fuzz.rs`).
+
+use std::fs;
+
+use aoc::fuzz;
+
+#[test]
+fn regressions() {
+    let crashes = fuzz::TARGETS
+        .iter()
+        .flat_map(|target| {
+            let dir = fuzz::regression_dir(target);
+            fs::read_dir(dir)
+                .into_iter()
+                .flatten()
+                .map(|entry| entry.unwrap().path())
+                .filter_map(move |path| {
+                    let input = fs::read(&path).unwrap();
+                    fuzz::crashes(target, &input)
+                        .map(|msg| format!("{}: {} ({msg})", target.name, path.display()))
+                })
+        })
+        .collect::<Vec<_>>();
+    assert!(crashes.is_empty(), "{}", crashes.join("\n"));
+}
@@ -0,0 +1,209 @@
+//! Runs the REPL commands on the examples.
+
+use std::{env, fs, process};
+
+use aoc::{
+    input::y2023::{day_02, day_03, day_04, day_05},
+    repl::Session,
+};
+
+const DAY_01: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+
+/// A session with an input for a day loaded from a file
+fn session(day: usize, input: &str) -> Session {
+    let path = env::temp_dir().join(format!("aoc-repl-{}-{day}.txt", process::id()));
+    fs::write(&path, input).unwrap();
+    let mut session = Session::new(2023);
+    let reply = session.execute(&format!("load {day} {}", path.display()));
+    fs::remove_file(&path).unwrap();
+    assert_eq!(
+        reply,
+        Ok(format!(
+            "loaded day {day} ({} lines)",
+            input.lines().count()
+        ))
+    );
+    session
+}
+
+#[test]
+fn general() {
+    let mut session = Session::new(2023);
+    assert_eq!(session.execute(""), Ok(String::new()));
+    assert!(session.execute("help").unwrap().contains("load DAY [PATH]"));
+    assert_eq!(
+        session.execute("line 1"),
+        Err("nothing loaded yet, try `load DAY`".to_string())
+    );
+    assert_eq!(
+        session.execute("solve"),
+        Err("nothing loaded yet, try `load DAY`".to_string())
+    );
+    assert_eq!(session.execute("load"), Err("missing day".to_string()));
+    assert_eq!(
+        session.execute("load x"),
+        Err("invalid day \"x\"".to_string())
+    );
+    assert_eq!(
+        session.execute("load 26"),
+        Err("2023 day 26 isn't implemented".to_string())
+    );
+    assert_eq!(
+        Session::new(2015).execute("load 1"),
+        Err("2015 day 1 isn't implemented".to_string())
+    );
+    // stdin is where the commands come from
+    assert_eq!(
+        session.execute("load 1 -"),
+        Err("can't load an input from stdin, use a file".to_string())
+    );
+}
+
+#[test]
+fn invalid_input() {
+    let path = env::temp_dir().join(format!("aoc-repl-{}-invalid.txt", process::id()));
+    fs::write(&path, "Game 1: 3 purple").unwrap();
+    let mut session = Session::new(2023);
+    let reply = session.execute(&format!("load 2 {}", path.display()));
+    fs::remove_file(&path).unwrap();
+    let err = reply.unwrap_err();
+    assert!(
+        err.starts_with("the input doesn't look like day 2"),
+        "{err}"
+    );
+    assert!(session.execute("game 1").is_err());
+}
+
+#[test]
+fn solve() {
+    let mut session = session(1, DAY_01);
+    assert_eq!(session.execute("solve 1"), Ok("part 1: 142".to_string()));
+    assert_eq!(
+        session.execute("solve"),
+        Ok("part 1: 142\npart 2: 142".to_string())
+    );
+    assert_eq!(
+        session.execute("solve 3"),
+        Err("there's no part 3".to_string())
+    );
+    assert_eq!(
+        session.execute("solve 0"),
+        Err("there's no part 0".to_string())
+    );
+}
+
+#[test]
+fn day_01() {
+    let mut session = session(1, DAY_01);
+    assert_eq!(
+        session.execute("line 2"),
+        Ok("pqr3stu8vwx\npart 1 digits: 38\npart 2 digits: 38".to_string())
+    );
+    assert_eq!(
+        session.execute("line 5"),
+        Err("there's no line 5".to_string())
+    );
+    assert_eq!(
+        session.execute("game 1"),
+        Err("unknown command \"game\" (see `help`)".to_string())
+    );
+}
+
+#[test]
+fn day_02() {
+    let mut session = session(2, day_02::TEST_INPUT);
+    assert_eq!(session.execute("game 3"),
+        Ok("game 3: 6 Blue, 8 Green, 20 Red; 5 Blue, 13 Green, 4 Red; 5 Green, 1 Red\nsmallest bag: 20 red, 13 green, 6 blue (power 1560)".to_string()));
+    assert_eq!(
+        session.execute("games red > 12"),
+        Ok("2 games: 3, 4".to_string())
+    );
+    assert_eq!(
+        session.execute("games blue <= 4"),
+        Ok("2 games: 2, 5".to_string())
+    );
+    assert_eq!(
+        session.execute("games red ~ 12"),
+        Err("unknown comparison \"~\"".to_string())
+    );
+    assert_eq!(
+        session.execute("game 6"),
+        Err("there's no game 6".to_string())
+    );
+}
+
+#[test]
+fn day_03() {
+    let mut session = session(3, day_03::TEST_INPUT);
+    assert_eq!(
+        session.execute("symbol 1 3"),
+        Ok("'*' touches\n  467 at (0, 0..=2)\n  35 at (2, 2..=3)".to_string())
+    );
+    assert_eq!(
+        session.execute("number 0 1"),
+        Ok("467 touches\n  '*' at (1, 3)".to_string())
+    );
+    assert_eq!(
+        session.execute("number 0 5"),
+        Ok("114 isn't a part number".to_string())
+    );
+    assert_eq!(
+        session.execute("gears"),
+        Ok("2 gears\n(1, 3): 467 * 35 = 16345\n(8, 5): 755 * 598 = 451490".to_string())
+    );
+    assert_eq!(
+        session.execute("symbol 0 0"),
+        Err("no symbol touching a number at (0, 0)".to_string())
+    );
+    assert_eq!(
+        session.execute("number 1"),
+        Err("missing column".to_string())
+    );
+}
+
+#[test]
+fn day_04() {
+    let mut session = session(4, day_04::TEST_INPUT);
+    assert_eq!(
+        session.execute("card 1"),
+        Ok("card 1: 41 48 83 86 17 | 83 86 6 31 17 9 48 53\n4 wins, 8 points".to_string())
+    );
+    assert_eq!(
+        session.execute("copies 4"),
+        Ok("8 copies of card 4, won from cards 1, 2, 3".to_string())
+    );
+    assert_eq!(
+        session.execute("copies 1"),
+        Ok("1 copies of card 1, won from no other card".to_string())
+    );
+    assert_eq!(
+        session.execute("card 7"),
+        Err("there's no card 7".to_string())
+    );
+}
+
+#[test]
+fn day_05() {
+    let mut session = session(5, day_05::TEST_INPUT);
+    assert_eq!(
+        session.execute("seeds"),
+        Ok("79 -> 82\n14 -> 43\n55 -> 86\n13 -> 35".to_string())
+    );
+    assert_eq!(session.execute("trace 79"),
+        Ok("seed 79\nseed-to-soil: 81\nsoil-to-fertilizer: 81\nfertilizer-to-water: 81\nwater-to-light: 74\nlight-to-temperature: 78\ntemperature-to-humidity: 78\nhumidity-to-location: 82".to_string()));
+    assert_eq!(
+        session.execute("map 1"),
+        Ok("seed-to-soil\n50..98 -> 52..100\n98..100 -> 50..52".to_string())
+    );
+    assert_eq!(
+        session.execute("map water-to-light"),
+        Ok("water-to-light\n18..25 -> 88..95\n25..95 -> 18..88".to_string())
+    );
+    assert_eq!(
+        session.execute("map 8"),
+        Err("there's no map \"8\"".to_string())
+    );
+    // a failed load keeps what was loaded
+    assert!(session.execute("load 1 /nonexistent").is_err());
+    assert!(session.execute("seeds").is_ok());
+}
@@ -0,0 +1,46 @@
+//! Locks down intermediate results of the solutions on the puzzle examples, see
+//! [`aoc::snapshot`] for how to update them.
+
+use std::str::FromStr;
+
+use aoc::{
+    input::y2023::{day_03, day_04, day_05},
+    snapshot::{assert_snapshot, diff},
+    y2023,
+};
+
+#[test]
+fn y2023_day_03_adjacencies() {
+    let schematic = y2023::day_03::Schematic::from_str(day_03::TEST_INPUT).unwrap();
+    assert_snapshot("y2023_day_03_adjacencies", &schematic.adjacencies());
+}
+
+#[test]
+fn y2023_day_04_wins() {
+    let wins = day_04::TEST_INPUT
+        .lines()
+        .map(|line| {
+            let card = y2023::day_04::Card::from_str(line).unwrap();
+            (card.id, card.count_wins())
+        })
+        .collect::<Vec<_>>();
+    assert_snapshot("y2023_day_04_wins", &wins);
+}
+
+#[test]
+fn y2023_day_05_maps() {
+    let almanac = y2023::day_05::Almanac::from_str(day_05::TEST_INPUT).unwrap();
+    assert_snapshot("y2023_day_05_maps", &almanac.maps);
+    let traces = almanac
+        .seeds
+        .iter()
+        .map(|&seed| (seed, almanac.trace(seed)))
+        .collect::<Vec<_>>();
+    assert_snapshot("y2023_day_05_traces", &traces);
+}
+
+#[test]
+fn diffs() {
+    assert_eq!(diff("a\nb\nc\n", "a\nx\nc\nd\n"), " a\n-b\n+x\n c\n+d\n");
+    assert_eq!(diff("same\n", "same\n"), " same\n");
+}
@@ -0,0 +1,206 @@
+//! Checks the input schemas on valid inputs and on inputs breaking each of the checks.
+
+use std::panic;
+
+use aoc::{
+    input::y2023::{day_02, day_03, day_04, day_05},
+    validate::{self, Severity},
+    y2023,
+};
+
+/// The checks an input violates, with their (1-based) lines
+fn violations(day: usize, input: &str) -> Vec<(&'static str, Option<usize>)> {
+    validate::schema(2023, day)
+        .unwrap()
+        .validate(input)
+        .into_iter()
+        .map(|violation| (violation.check, violation.line))
+        .collect()
+}
+
+#[test]
+fn valid() {
+    for (day, input) in [
+        (1, "1abc2\npqr3stu8vwx\ntwo1nine"),
+        (2, day_02::TEST_INPUT),
+        (3, day_03::TEST_INPUT),
+        (4, day_04::TEST_INPUT),
+        (5, day_05::TEST_INPUT),
+    ] {
+        assert_eq!(violations(day, input), [], "day {day}");
+    }
+}
+
+#[test]
+fn day_01() {
+    assert_eq!(violations(1, ""), [("no-blank-lines", None)]);
+    assert_eq!(violations(1, "1\n\n2"), [("no-blank-lines", Some(2))]);
+    assert_eq!(violations(1, "1\ntwone"), [("has-digit", Some(2))]);
+    assert_eq!(
+        violations(1, "abc"),
+        [("has-digit", Some(1)), ("has-digit-or-numeral", Some(1))]
+    );
+    assert_eq!(violations(1, "zero1"), [("no-zero-numeral", Some(1))]);
+}
+
+#[test]
+fn day_02() {
+    assert_eq!(
+        violations(2, "Game 1: 3 purple"),
+        [("game-syntax", Some(1))]
+    );
+    assert_eq!(
+        violations(2, "Game 1: 1 red\nGame 2: 3 blue, 4 blue; 1 red"),
+        [("unique-colors-per-draw", Some(2))]
+    );
+    assert_eq!(
+        violations(2, "Game 1: 99999999999999999999999 red"),
+        [("numbers-fit", Some(1))]
+    );
+    assert_eq!(
+        violations(2, "Game 1: 1 red\n\nGame 2: 1 red"),
+        [("no-blank-lines", Some(2))]
+    );
+    assert_eq!(
+        violations(
+            2,
+            "Game 1: 1 red\nGame 2: 9999999999 red, 9999999999 green, 9999999999 blue"
+        ),
+        [("powers-fit", Some(2))]
+    );
+}
+
+#[test]
+fn day_03() {
+    assert_eq!(violations(3, "467..\n..*\n"), [("rectangular", Some(2))]);
+    assert_eq!(violations(3, "1 .\n..."), [("no-whitespace", Some(1))]);
+    assert_eq!(
+        violations(3, "99999999999\n..........."),
+        [("numbers-fit", Some(1))]
+    );
+    assert!(violations(3, "4é.\n...").contains(&("ascii", Some(1))));
+    assert_eq!(violations(3, ""), [("rectangular", None)]);
+}
+
+#[test]
+fn day_04() {
+    assert_eq!(violations(4, "Card 1: 1 2"), [("card-syntax", Some(1))]);
+    assert_eq!(
+        violations(4, "Card 1: 1 | 2\nCard 3: 1 | 2"),
+        [("consecutive-ids", Some(2))]
+    );
+    assert_eq!(
+        violations(4, "Card 1: 1 1 | 2"),
+        [("unique-numbers", Some(1))]
+    );
+    // 65 wins are worth 2^64 points
+    assert_eq!(
+        violations(4, &format!("Card 1: 1 |{}", " 1".repeat(65))),
+        [("unique-numbers", Some(1)), ("points-fit", Some(1))]
+    );
+    // with 2 wins on every card the copies grow like the Fibonacci numbers
+    let cards = (1..=100)
+        .map(|id| format!("Card {id}: 1 2 | 1 2"))
+        .collect::<Vec<_>>();
+    assert_eq!(violations(4, &cards[..80].join("\n")), []);
+    y2023::day_04::snd(&cards[..80].join("\n"));
+    assert_eq!(violations(4, &cards.join("\n")), [("copies-fit", Some(90))]);
+}
+
+#[test]
+fn day_05() {
+    let map = "\n\nseed-to-location map:\n0 0 10";
+    assert_eq!(
+        violations(5, &format!("seeds: 1 2 3{map}")),
+        [("seed-pairs", Some(1))]
+    );
+    assert_eq!(
+        violations(5, &format!("seed: 1 2{map}")),
+        [("seeds", Some(1))]
+    );
+    assert_eq!(violations(5, "seeds: 1 2"), [("map-syntax", None)]);
+    // part 2 needs a seed range that isn't empty
+    assert_eq!(
+        violations(5, &format!("seeds: 1{map}")),
+        [("seed-pairs", Some(1)), ("seed-ranges", Some(1))]
+    );
+    assert_eq!(
+        violations(5, &format!("seeds: 1 0 2 0{map}")),
+        [("seed-ranges", Some(1))]
+    );
+    assert_eq!(violations(5, &format!("seeds: 1 0 2 1{map}")), []);
+    assert_eq!(
+        violations(5, "seeds: 1 2\n\nseed-to-location map:\n1 2"),
+        [("map-syntax", Some(4))]
+    );
+    assert_eq!(
+        violations(5, "seeds: 1 2\n\nseed-to-location map:\n\nx"),
+        [
+            ("map-syntax", Some(3)),
+            ("map-syntax", Some(5)),
+            ("map-syntax", Some(5)),
+        ]
+    );
+    assert_eq!(
+        violations(
+            5,
+            "seeds: 1 2\n\nseed-to-soil map:\n0 0 1\n\nwater-to-location map:\n0 0 1"
+        ),
+        [("category-chain", Some(6))]
+    );
+    assert_eq!(
+        violations(5, "seeds: 1 2\n\nseed-to-soil map:\n0 0 1"),
+        [("category-chain", None)]
+    );
+    assert_eq!(
+        violations(5, "seeds: 1 2\n\nseed-to-location map:\n0 0 10\n20 5 10"),
+        [("non-overlapping", Some(5))]
+    );
+    assert_eq!(
+        violations(
+            5,
+            "seeds: 1 2\n\nseed-to-location map:\n0 0 99999999999999999999999"
+        ),
+        [("map-syntax", Some(4)), ("numbers-fit", Some(4))]
+    );
+    // the ends of the ranges have to fit too
+    assert_eq!(
+        violations(5, &format!("seeds: 18446744073709551615 2{map}")),
+        [("ranges-fit", Some(1))]
+    );
+    assert_eq!(
+        violations(
+            5,
+            "seeds: 1 2\n\nseed-to-location map:\n18446744073709551615 0 10"
+        ),
+        [("ranges-fit", Some(4))]
+    );
+    assert_eq!(
+        violations(
+            5,
+            "seeds: 1 2\n\nseed-to-location map:\n0 18446744073709551610 10\n0 0 5"
+        ),
+        [("ranges-fit", Some(4))]
+    );
+    assert_eq!(
+        violations(
+            5,
+            &format!("seeds: 18446744073709551605 10 5 2{map}\n18446744073709551605 10 10")
+        ),
+        []
+    );
+}
+
+#[test]
+fn enforce() {
+    let schema = validate::schema(2023, 1).unwrap();
+    // warnings are only reported
+    schema.enforce("1\ntwone");
+    let result = panic::catch_unwind(|| schema.enforce("abc"));
+    assert!(result.is_err());
+    assert!(schema
+        .validate("abc")
+        .iter()
+        .any(|violation| violation.severity == Severity::Error));
+    assert!(validate::schema(2023, 26).is_none());
+}
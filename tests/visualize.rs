@@ -0,0 +1,428 @@
+//! Checks the image encoders, the visualizations of the examples, the terminal player and
+//! the SVG drawings.
+
+use std::{collections::HashMap, env, fs, sync::mpsc, time::Duration};
+
+use aoc::{
+    input::y2023::{day_02, day_03, day_04, day_05},
+    rng::Rng,
+    visualize::{
+        self, gif, png, ppm,
+        svg::{self, Element, Svg},
+        terminal::{self, Command, Player},
+        y2023::day_04::Cascade,
+        Color, Format, Frame,
+    },
+};
+
+/// A frame with a pixel of every color of a palette per row
+fn striped(palette: &[Color], width: usize) -> Frame {
+    let mut frame = Frame::new(width, palette.len(), Color::BLACK);
+    for (y, color) in palette.iter().enumerate() {
+        frame.fill(0, y, width, 1, *color);
+    }
+    frame
+}
+
+#[test]
+fn ppm() {
+    let mut frame = Frame::new(2, 1, Color::RED);
+    frame.set(1, 0, Color(1, 2, 3));
+    assert_eq!(
+        ppm::encode(&frame),
+        b"P6\n2 1\n255\n\xdc\x32\x2f\x01\x02\x03"
+    );
+}
+
+#[test]
+fn png_checksums() {
+    assert_eq!(png::crc32(b"IEND"), 0xae42_6082);
+    assert_eq!(png::adler32(b"Wikipedia"), 0x11e6_0398);
+}
+
+#[test]
+fn png() {
+    let frame = striped(&[Color::RED, Color::GREEN, Color::BLUE], 300);
+    let bytes = png::encode(&frame);
+    assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+    let mut chunks = vec![];
+    let mut rest = &bytes[8..];
+    while !rest.is_empty() {
+        let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let (kind, data) = (&rest[4..8], &rest[8..8 + len]);
+        let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+        assert_eq!(png::crc32(&rest[4..8 + len]), crc);
+        chunks.push((kind.to_vec(), data.to_vec()));
+        rest = &rest[12 + len..];
+    }
+    let kinds = chunks
+        .iter()
+        .map(|(kind, _)| kind.as_slice())
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, [b"IHDR", b"IDAT", b"IEND"]);
+    assert_eq!(&chunks[0].1[..8], [0, 0, 1, 44, 0, 0, 0, 3]);
+
+    // a single stored block holds the scanlines as they are
+    let idat = &chunks[1].1;
+    let raw = &idat[7..idat.len() - 4];
+    assert_eq!(idat[2], 1);
+    assert_eq!(raw.len(), 3 * (1 + 3 * 300));
+    assert_eq!(&raw[..4], [0, 220, 50, 47]);
+    assert_eq!(idat[idat.len() - 4..], png::adler32(raw).to_be_bytes());
+}
+
+/// Undo [`gif::lzw`]
+fn unlzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1usize << min_code_size;
+    let mut table: Vec<Vec<u8>> = vec![];
+    let mut size = min_code_size + 1;
+    let (mut buffer, mut bits, mut bytes) = (0u32, 0, data.iter());
+    let mut prev: Option<Vec<u8>> = None;
+    let mut out = vec![];
+    loop {
+        while bits < size {
+            buffer |= (*bytes.next().unwrap() as u32) << bits;
+            bits += 8;
+        }
+        let code = (buffer & ((1 << size) - 1)) as usize;
+        (buffer, bits) = (buffer >> size, bits - size);
+        if code == clear {
+            table = (0..clear + 2).map(|i| vec![i as u8]).collect();
+            size = min_code_size + 1;
+            prev = None;
+            continue;
+        }
+        if code == clear + 1 {
+            return out;
+        }
+        let entry = match (table.get(code), &prev) {
+            (Some(entry), _) => entry.clone(),
+            (None, Some(prev)) => [prev.as_slice(), &prev[..1]].concat(),
+            (None, None) => panic!("unknown first code {code}"),
+        };
+        out.extend(&entry);
+        if let Some(prev) = prev {
+            if table.len() < 4096 {
+                table.push([prev.as_slice(), &entry[..1]].concat());
+                if table.len() == 1 << size && size < 12 {
+                    size += 1;
+                }
+            }
+        }
+        prev = Some(entry);
+    }
+}
+
+#[test]
+fn lzw() {
+    let mut rng = Rng::seed_from(0);
+    for colors in [2, 4, 16, 256] {
+        let bits = (colors as f64).log2().max(2.0) as u8;
+        // long enough to fill the table a few times
+        let indices = (0..40_000)
+            .map(|i| if i % 7 == 0 { rng.below(colors) } else { i / 300 % colors } as u8)
+            .collect::<Vec<_>>();
+        assert_eq!(unlzw(&gif::lzw(&indices, bits), bits), indices);
+    }
+    assert!(unlzw(&gif::lzw(&[], 2), 2).is_empty());
+}
+
+#[test]
+fn gif() {
+    let frames = [
+        striped(&[Color::RED, Color::GREEN, Color::BLUE], 5),
+        striped(&[Color::BLUE, Color::GREEN, Color::RED], 5),
+    ];
+    let bytes = gif::encode(&frames, 25);
+    assert_eq!(&bytes[..6], b"GIF89a");
+    assert_eq!(&bytes[6..10], [5, 0, 3, 0]);
+    // 3 colors take a table of 4
+    assert_eq!(bytes[10], 0x80 | 0x10 | 1);
+    let palette = bytes[13..25]
+        .chunks(3)
+        .map(|rgb| Color(rgb[0], rgb[1], rgb[2]))
+        .collect::<Vec<_>>();
+    assert_eq!(*bytes.last().unwrap(), 0x3b);
+
+    // follow the blocks to the image data of both frames
+    let mut pos = 25;
+    let mut images = vec![];
+    while bytes[pos] != 0x3b {
+        let mut data = vec![];
+        let min_code_size = match bytes[pos] {
+            0x21 => {
+                if bytes[pos + 1] == 0xf9 {
+                    assert_eq!(&bytes[pos + 4..pos + 6], 25u16.to_le_bytes());
+                }
+                pos += 2;
+                None
+            }
+            0x2c => {
+                pos += 11;
+                Some(bytes[pos - 1])
+            }
+            other => panic!("unexpected block {other:#x}"),
+        };
+        while bytes[pos] != 0 {
+            data.extend(&bytes[pos + 1..pos + 1 + bytes[pos] as usize]);
+            pos += 1 + bytes[pos] as usize;
+        }
+        pos += 1;
+        if let Some(min_code_size) = min_code_size {
+            images.push(unlzw(&data, min_code_size));
+        }
+    }
+    let decoded = images
+        .iter()
+        .map(|indices| {
+            indices
+                .iter()
+                .map(|i| palette[*i as usize])
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        decoded,
+        [frames[0].pixels.clone(), frames[1].pixels.clone()]
+    );
+}
+
+#[test]
+fn gif_reduces_colors() {
+    let palette = (0..300)
+        .map(|i| Color(i as u8, (i / 2) as u8, 7))
+        .collect::<Vec<_>>();
+    let bytes = gif::encode(&[striped(&palette, 1)], 10);
+    // only the top bits of red and green tell them apart then, which leaves few enough
+    // colors for a table of 16
+    assert_eq!(bytes[10] & 7, 3);
+}
+
+fn counts(frame: &Frame) -> HashMap<Color, usize> {
+    let mut counts = HashMap::new();
+    for pixel in &frame.pixels {
+        *counts.entry(*pixel).or_default() += 1;
+    }
+    counts
+}
+
+#[test]
+fn schematic() {
+    let frames = visualize::frames(2023, 3, day_03::TEST_INPUT).unwrap();
+    let last = frames.last().unwrap();
+    // the last column is all dots, which the schematic doesn't keep
+    assert_eq!((last.width, last.height), (9, 10));
+    // 467 and 35 are the gear's, 114 isn't a part number and 633 is
+    assert_eq!(last.get(0, 0), Color::YELLOW);
+    assert_eq!(last.get(3, 1), Color::YELLOW);
+    assert_eq!(last.get(5, 0), Color::DIM);
+    assert_eq!(last.get(6, 2), Color::GREEN);
+    assert_eq!(last.get(6, 3), Color::WHITE);
+    assert_eq!(counts(last)[&Color::DIM], 5);
+    // the first frame is the bare schematic
+    assert_eq!(frames[0].get(0, 0), Color::DIM);
+    assert_eq!(frames[0].get(3, 1), Color::RED);
+}
+
+#[test]
+fn cascade() {
+    let cascade = day_04::TEST_INPUT.parse::<Cascade>().unwrap();
+    assert_eq!(cascade.wins, [4, 2, 2, 1, 0, 0]);
+    assert_eq!(cascade.copies(6), [1, 2, 4, 8, 14, 1]);
+    let frames = visualize::frames(2023, 4, day_04::TEST_INPUT).unwrap();
+    assert_eq!(frames.len(), 7);
+    // the tallest bar is card 5 at the end, after the first card every card it won
+    let last = frames.last().unwrap();
+    assert_eq!(last.get(4, 0), Color::BLUE);
+    assert_eq!(frames[1].get(0, last.height - 1), Color::WHITE);
+    assert_eq!(frames[1].get(4, last.height - 1), Color::GREEN);
+    assert_eq!(frames[1].get(5, last.height - 1), Color::DIM);
+}
+
+#[test]
+fn almanac() {
+    let frames = visualize::frames(2023, 5, day_05::TEST_INPUT).unwrap();
+    assert_eq!(frames.len(), 7);
+    assert!(frames
+        .iter()
+        .all(|frame| counts(frame).contains_key(&Color::WHITE)));
+    assert!(visualize::frames(2023, 1, "").is_none());
+}
+
+#[test]
+fn export() {
+    let dir = env::temp_dir().join(format!("aoc_visualize_{}", std::process::id()));
+    let frames = visualize::frames(2023, 4, day_04::TEST_INPUT).unwrap();
+    visualize::export(&frames, Format::Png, &dir.join("png"), 10).unwrap();
+    visualize::export(&frames, Format::Gif, &dir.join("cascade.gif"), 10).unwrap();
+    assert_eq!(fs::read_dir(dir.join("png")).unwrap().count(), frames.len());
+    assert!(dir.join("png/frame_0006.png").exists());
+    assert!(fs::read(dir.join("cascade.gif"))
+        .unwrap()
+        .starts_with(b"GIF89a"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn render() {
+    let mut frame = Frame::new(2, 3, Color::RED);
+    frame.set(1, 1, Color::BLUE);
+    let red = "\x1b[38;2;220;50;47m";
+    assert_eq!(
+        terminal::render(&frame),
+        format!(
+            "{red}\x1b[48;2;220;50;47m▀{red}\x1b[48;2;38;139;210m▀\x1b[0m\n\
+             {red}\x1b[49m▀▀\x1b[0m\n"
+        )
+    );
+}
+
+#[test]
+fn keys() {
+    assert_eq!(
+        Command::parse(b" nx\x1b[C\x1b[Dp+-\x1bq"),
+        [
+            Command::TogglePause,
+            Command::Step,
+            Command::Step,
+            Command::Back,
+            Command::Back,
+            Command::Faster,
+            Command::Slower,
+            Command::Quit
+        ]
+    );
+}
+
+#[test]
+fn player() {
+    let frames = visualize::frames(2023, 4, day_04::TEST_INPUT).unwrap();
+    let mut player = Player::new(frames, Duration::from_millis(100));
+    assert!(player.apply(Command::Back));
+    assert_eq!((player.index, player.paused), (6, true));
+    player.apply(Command::Step);
+    player.apply(Command::Step);
+    assert_eq!(player.index, 1);
+    player.apply(Command::TogglePause);
+    assert!(!player.paused);
+    player.apply(Command::Faster);
+    assert_eq!(player.wait(), Duration::from_millis(50));
+    for _ in 0..10 {
+        player.apply(Command::Slower);
+    }
+    assert_eq!(player.wait(), Duration::from_millis(800));
+    assert!(!player.apply(Command::Quit));
+}
+
+#[test]
+fn play() {
+    let frames = visualize::frames(2023, 4, day_04::TEST_INPUT).unwrap();
+    let status = |out: &[u8]| {
+        String::from_utf8_lossy(out)
+            .split("\x1b[H")
+            .filter_map(|drawn| drawn.split("frame ").nth(1)?.split("  (").next())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    // without anyone at the controls it plays once
+    let (tx, rx) = mpsc::channel();
+    drop(tx);
+    let mut out = vec![];
+    Player::new(frames.clone(), Duration::ZERO)
+        .play(&mut out, &rx)
+        .unwrap();
+    assert_eq!(status(&out).len(), frames.len());
+    assert_eq!(status(&out).last().unwrap(), "7/7  1x");
+
+    let (tx, rx) = mpsc::channel();
+    for command in [Command::Step, Command::Step, Command::Back, Command::Quit] {
+        tx.send(command).unwrap();
+    }
+    let mut out = vec![];
+    Player::new(frames, Duration::from_secs(60))
+        .play(&mut out, &rx)
+        .unwrap();
+    assert_eq!(
+        status(&out),
+        [
+            "1/7  1x",
+            "2/7  1x  paused",
+            "3/7  1x  paused",
+            "2/7  1x  paused"
+        ]
+    );
+}
+
+#[test]
+fn svg_elements() {
+    assert_eq!(svg::escape(r#"<a & "b">"#), "&lt;a &amp; &quot;b&quot;&gt;");
+    assert_eq!(Color(255, 16, 0).hex(), "#ff1000");
+    assert_eq!(
+        svg::rect(1.0, 2.5, 3.0, 4.0).fill(Color::WHITE).to_string(),
+        r##"<rect x="1" y="2.5" width="3" height="4" fill="#ffffff"/>"##
+    );
+    assert_eq!(
+        svg::text(0.0, 0.0, 8.0, "middle", "a<b")
+            .title("1 & 2")
+            .to_string(),
+        r#"<text x="0" y="0" font-size="8" text-anchor="middle"><title>1 &amp; 2</title>a&lt;b</text>"#
+    );
+    assert_eq!(
+        Element::new("g").attr("id", "\"x\"").to_string(),
+        r#"<g id="&quot;x&quot;"/>"#
+    );
+}
+
+/// How often an element occurs in a drawing, checking that it's a standalone SVG
+fn elements(svg: &Svg, name: &str) -> usize {
+    let svg = svg.to_string();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg""#));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    for external in [
+        "<script",
+        "<style",
+        "@import",
+        "@font-face",
+        "href=",
+        "url(http",
+    ] {
+        assert!(!svg.contains(external), "{external}");
+    }
+    svg.matches(&format!("<{name} ")).count()
+}
+
+#[test]
+fn drawings() {
+    // 3 bars for each of the 5 games and the 3 limits
+    let games = visualize::drawing(2023, 2, day_02::TEST_INPUT).unwrap();
+    assert_eq!(elements(&games, "rect"), 1 + 15);
+    assert_eq!(elements(&games, "line"), 3);
+    let svg = games.to_string();
+    assert!(svg.contains("Game 3: at least 20 red"));
+    assert!(svg.contains("not possible with the bag, power 1560"));
+
+    // 10 numbers and 6 symbols, 2 of them gears
+    let schematic = visualize::drawing(2023, 3, day_03::TEST_INPUT).unwrap();
+    assert_eq!(
+        (schematic.width, schematic.height),
+        (9.0 * 14.0, 10.0 * 14.0)
+    );
+    assert_eq!(elements(&schematic, "rect"), 1 + 10);
+    assert_eq!(elements(&schematic, "text"), 10 + 6);
+    assert_eq!(elements(&schematic, "circle"), 2);
+    let svg = schematic.to_string();
+    assert!(svg.contains("114 (not a part number)"));
+    assert!(svg.contains("gear ratio 467 * 35 = 16345"));
+
+    // 7 maps of 2 to 4 ranges with an arrow each, and 4 seeds
+    let almanac = visualize::drawing(2023, 5, day_05::TEST_INPUT).unwrap();
+    assert_eq!(elements(&almanac, "rect"), 1 + 2 * 18);
+    assert_eq!(elements(&almanac, "line"), 7 * 2 + 18 + 7 * 2 * 4);
+    assert_eq!(almanac.to_string().matches("url(#arrow)").count(), 18);
+    assert!(almanac
+        .to_string()
+        .contains("seed-to-soil: 98..100 to 50..52"));
+
+    assert!(visualize::drawing(2023, 4, day_04::TEST_INPUT).is_none());
+}